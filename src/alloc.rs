@@ -0,0 +1,150 @@
+use std::cell::Cell;
+
+use crate::runner::Allocations;
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Counters {
+    count: u64,
+    bytes: u64,
+    /// Signed since memory can be freed on another thread than the one that
+    /// allocated it.
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            count: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+
+    use super::COUNTERS;
+
+    fn record(allocated: usize, freed: usize) {
+        // the counters are gone while the thread is being torn down
+        let _ = COUNTERS.try_with(|cell| {
+            let mut counters = cell.get();
+            if allocated > 0 {
+                counters.count += 1;
+                counters.bytes += allocated as u64;
+            }
+            counters.live += allocated as i64 - freed as i64;
+            counters.peak = counters.peak.max(counters.live);
+            cell.set(counters);
+        });
+    }
+
+    /// The system allocator, counting allocations on the thread making them.
+    pub struct CountingAlloc;
+
+    unsafe impl GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            record(layout.size(), 0);
+            System.alloc(layout)
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            record(layout.size(), 0);
+            System.alloc_zeroed(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            record(0, layout.size());
+            System.dealloc(ptr, layout)
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            record(new_size, layout.size());
+            System.realloc(ptr, layout, new_size)
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAlloc = CountingAlloc;
+}
+
+/// Runs `f` and counts the allocations it made on this thread. Only counted
+/// when built with the `alloc-stats` feature, `None` otherwise.
+pub fn track<T>(f: impl FnOnce() -> T) -> (T, Option<Allocations>) {
+    if !cfg!(feature = "alloc-stats") {
+        return (f(), None);
+    }
+
+    let before = COUNTERS.with(|cell| {
+        let mut counters = cell.get();
+        counters.peak = counters.live;
+        cell.set(counters);
+        counters
+    });
+    let result = f();
+    let after = COUNTERS.with(Cell::get);
+
+    let allocations = Allocations {
+        count: after.count - before.count,
+        bytes: after.bytes - before.bytes,
+        peak: (after.peak - before.live).max(0) as u64,
+    };
+    (result, Some(allocations))
+}
+
+/// Formats a byte count with a binary unit, like `1.5 KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    let units = ["KiB", "MiB", "GiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < units.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, units[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    #[cfg(not(feature = "alloc-stats"))]
+    fn tracks_nothing_without_feature() {
+        let (result, allocations) = track(|| vec![1u8; 64].len());
+
+        assert_eq!(result, 64);
+        assert_eq!(allocations, None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc-stats")]
+    fn tracks_allocations() {
+        let (_, allocations) = track(|| {
+            let first = vec![0u8; 1000];
+            drop(first);
+            let second = vec![0u8; 600];
+            second.len()
+        });
+        let allocations = allocations.unwrap();
+
+        assert_eq!(allocations.count, 2);
+        assert_eq!(allocations.bytes, 1600);
+        assert_eq!(allocations.peak, 1000);
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::runner::year_dir;
+
+/// Known correct answers keyed by `(day, part)`.
+pub type Answers = BTreeMap<(i32, i32), String>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+pub fn answers_path(year: i32) -> PathBuf {
+    year_dir(year).join("answers.txt")
+}
+
+/// Parses lines of the form `day part answer`, skipping blank lines and
+/// `#` comments.
+pub fn parse_answers(text: &str) -> Answers {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (day, rest) = line.split_once(char::is_whitespace)?;
+            let (part, answer) = rest.trim_start().split_once(char::is_whitespace)?;
+            let day = day.parse().ok()?;
+            let part = part.parse().ok()?;
+            Some(((day, part), answer.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Reads the event's answers file, `None` if there isn't one.
+pub fn read_answers(year: i32) -> Option<Answers> {
+    fs::read_to_string(answers_path(year))
+        .ok()
+        .map(|text| parse_answers(&text))
+}
+
+pub fn check(answers: &Answers, day: i32, part: i32, answer: &str) -> Verdict {
+    match answers.get(&(day, part)) {
+        Some(expected) if expected == answer => Verdict::Pass,
+        Some(_) => Verdict::Fail,
+        None => Verdict::Unknown,
+    }
+}
+
+/// Appends the verdict to an answer when there is an answers file.
+pub fn with_verdict(answers: Option<&Answers>, day: i32, part: i32, answer: String) -> String {
+    match answers {
+        Some(answers) => {
+            let verdict = check(answers, day, part, &answer);
+            format!("{} [{}]", answer, verdict)
+        }
+        None => answer,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"# day part answer
+1 1 142
+1 2   281
+
+9 2 -3
+bad line
+"#;
+
+    #[test]
+    fn parses_answers() {
+        let answers = parse_answers(EXAMPLE);
+
+        assert_eq!(answers.len(), 3);
+        assert_eq!(answers[&(1, 2)], "281");
+        assert_eq!(answers[&(9, 2)], "-3");
+    }
+
+    #[test]
+    fn checks_answers() {
+        let answers = parse_answers(EXAMPLE);
+
+        assert_eq!(check(&answers, 1, 1, "142"), Verdict::Pass);
+        assert_eq!(check(&answers, 1, 1, "143"), Verdict::Fail);
+        assert_eq!(check(&answers, 2, 1, "8"), Verdict::Unknown);
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarizes a non-empty list of samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        let n = samples.len();
+
+        let median = if n.is_multiple_of(2) {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        } else {
+            samples[n / 2]
+        };
+
+        // nearest-rank percentile
+        let p95_rank = (n * 95).div_ceil(100);
+        let p95 = samples[p95_rank.saturating_sub(1)];
+
+        let mean_secs = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean_secs).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Stats {
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean_secs),
+            p95,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Runs `f` `warmup` times without recording, then `samples` times
+/// recording how long each call took. The result of `f` is discarded.
+pub fn measure<T>(mut f: impl FnMut() -> T, warmup: usize, samples: usize) -> Stats {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+
+    let timings = (0..samples)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+
+    Stats::from_samples(timings)
+}
+
+/// Where the baseline of an event is kept, `target/aoc-bench/<year>/baseline.txt`.
+pub fn baseline_path(year: i32) -> PathBuf {
+    Path::new("target/aoc-bench")
+        .join(year.to_string())
+        .join("baseline.txt")
+}
+
+/// Median timings keyed by `(day, part)`, where part `0` is parsing.
+pub type Baseline = BTreeMap<(i32, i32), Duration>;
+
+/// Parses baseline lines of the form `day part median_nanos`, ignoring
+/// anything that doesn't match.
+pub fn parse_baseline(text: &str) -> Baseline {
+    text.lines()
+        .filter_map(|line| {
+            let mut tokens = line.split_ascii_whitespace();
+            let day = tokens.next()?.parse().ok()?;
+            let part = tokens.next()?.parse().ok()?;
+            let nanos = tokens.next()?.parse().ok()?;
+            Some(((day, part), Duration::from_nanos(nanos)))
+        })
+        .collect()
+}
+
+pub fn read_baseline(path: &Path) -> io::Result<Baseline> {
+    fs::read_to_string(path).map(|text| parse_baseline(&text))
+}
+
+pub fn write_baseline(path: &Path, baseline: &Baseline) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let contents: String = baseline
+        .iter()
+        .map(|((day, part), median)| format!("{} {} {}\n", day, part, median.as_nanos()))
+        .collect();
+    fs::write(path, contents)
+}
+
+/// Relative change of `current` compared to `baseline`, in percent. A zero
+/// baseline can't be compared with.
+pub fn change_percent(baseline: Duration, current: Duration) -> Option<f64> {
+    if baseline.is_zero() {
+        return None;
+    }
+    Some((current.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0)
+}
+
+/// Names a benchmarked step, part 0 being the parse, with `prefix` before
+/// the number of a part.
+pub fn step_name(part: i32, prefix: &str) -> String {
+    match part {
+        0 => String::from("parse"),
+        part => format!("{}{}", prefix, part),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calculates_stats() {
+        let samples = (1..=20).map(Duration::from_millis).collect();
+        let stats = Stats::from_samples(samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(10_500));
+        assert_eq!(stats.mean, Duration::from_micros(10_500));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+        assert_eq!(stats.stddev.as_micros(), 5766);
+    }
+
+    #[test]
+    fn calculates_stats_single_sample() {
+        let stats = Stats::from_samples(vec![Duration::from_millis(3)]);
+
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(3));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn parses_baseline() {
+        let baseline = parse_baseline("1 1 1500\n1 2 2000\ngarbage\n9 2 7\n");
+
+        assert_eq!(baseline.len(), 3);
+        assert_eq!(baseline[&(1, 1)], Duration::from_nanos(1500));
+        assert_eq!(baseline[&(9, 2)], Duration::from_nanos(7));
+    }
+
+    #[test]
+    fn calculates_change() {
+        let change = change_percent(Duration::from_millis(10), Duration::from_millis(12));
+
+        assert!((change.unwrap() - 20.0).abs() < 1e-9);
+        assert_eq!(
+            change_percent(Duration::ZERO, Duration::from_millis(1)),
+            None
+        );
+    }
+}
@@ -0,0 +1,802 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::config::Config;
+use aoc2023::ledger::Feedback;
+use aoc2023::output::Format;
+use aoc2023::runner::{parse_days, parse_year, DayError};
+use aoc2023::DEFAULT_YEAR;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Run,
+    Bench,
+    Verify,
+    New,
+    Watch,
+    Example,
+    Answer,
+    Config,
+    ImportExamples,
+    Gen,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DaySelection {
+    All,
+    Days(Vec<i32>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Args {
+    pub command: Command,
+    pub year: i32,
+    pub input_dir: Option<PathBuf>,
+    pub days: DaySelection,
+    pub samples: usize,
+    pub warmup: usize,
+    pub part: Option<i32>,
+    pub input: Option<PathBuf>,
+    pub save_baseline: bool,
+    pub threshold: f64,
+    pub jobs: usize,
+    pub timeout: Option<Duration>,
+    pub format: Format,
+    pub watch: Vec<PathBuf>,
+    pub input_name: Option<String>,
+    pub all_inputs: bool,
+    pub raw_input: bool,
+    pub guess: Option<String>,
+    pub feedback: Option<Feedback>,
+    pub page: Option<PathBuf>,
+    pub seed: u64,
+    pub size: usize,
+}
+
+#[derive(Debug, Clone)]
+pub enum ArgsError {
+    MissingDays,
+    UnexpectedArgument(String),
+    UnknownFlag(String),
+    MissingValue(String),
+    InvalidValue(String, String),
+    SingleDayOnly(String),
+    Conflict(String, String),
+    Requires(String, String),
+    Day(DayError),
+    Year(DayError),
+}
+
+impl Display for ArgsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArgsError::MissingDays => writeln!(f, "missing day to run."),
+            ArgsError::UnexpectedArgument(arg) => writeln!(f, "unexpected argument `{}`", arg),
+            ArgsError::UnknownFlag(flag) => writeln!(f, "unknown flag `{}`", flag),
+            ArgsError::MissingValue(flag) => writeln!(f, "flag `{}` expects a value", flag),
+            ArgsError::InvalidValue(flag, value) => {
+                writeln!(f, "invalid value `{}` for flag `{}`", value, flag)
+            }
+            ArgsError::SingleDayOnly(what) => {
+                writeln!(f, "`{}` can only be used with a single day", what)
+            }
+            ArgsError::Conflict(first, second) => {
+                writeln!(f, "`{}` cannot be used with `{}`", first, second)
+            }
+            ArgsError::Requires(first, second) => {
+                writeln!(f, "`{}` requires `{}`", first, second)
+            }
+            ArgsError::Day(e) => write!(f, "failed to parse day: {}", e),
+            ArgsError::Year(e) => write!(f, "failed to parse year: {}", e),
+        }
+    }
+}
+
+impl From<DayError> for ArgsError {
+    fn from(e: DayError) -> Self {
+        ArgsError::Day(e)
+    }
+}
+
+fn next_value<'a>(
+    args: &mut impl Iterator<Item = &'a String>,
+    flag: &str,
+) -> Result<&'a String, ArgsError> {
+    args.next()
+        .ok_or_else(|| ArgsError::MissingValue(flag.to_string()))
+}
+
+pub fn parse_value<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, ArgsError> {
+    value
+        .parse()
+        .map_err(|_| ArgsError::InvalidValue(flag.to_string(), value.to_string()))
+}
+
+pub fn parse_count(flag: &str, value: &str) -> Result<usize, ArgsError> {
+    match parse_value(flag, value)? {
+        0 => Err(ArgsError::InvalidValue(flag.to_string(), value.to_string())),
+        count => Ok(count),
+    }
+}
+
+fn parse_part(flag: &str, value: &str) -> Result<i32, ArgsError> {
+    match value {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(ArgsError::InvalidValue(flag.to_string(), value.to_string())),
+    }
+}
+
+/// Parses a duration like `500ms`, `2s` or `1.5m`, seconds without a unit.
+pub fn parse_duration(flag: &str, value: &str) -> Result<Duration, ArgsError> {
+    let (number, unit_secs) = if let Some(number) = value.strip_suffix("ms") {
+        (number, 0.001)
+    } else if let Some(number) = value.strip_suffix('s') {
+        (number, 1.0)
+    } else if let Some(number) = value.strip_suffix('m') {
+        (number, 60.0)
+    } else {
+        (value, 1.0)
+    };
+
+    let number: f64 = parse_value(flag, number)?;
+    // too large for a duration or so small it rounds down to nothing
+    match Duration::try_from_secs_f64(number * unit_secs) {
+        Ok(duration) if !duration.is_zero() => Ok(duration),
+        _ => Err(ArgsError::InvalidValue(flag.to_string(), value.to_string())),
+    }
+}
+
+/// Parses a percentage like `2.5`, which can't be negative.
+pub fn parse_percent(flag: &str, value: &str) -> Result<f64, ArgsError> {
+    let percent: f64 = parse_value(flag, value)?;
+    if percent.is_finite() && percent >= 0.0 {
+        Ok(percent)
+    } else {
+        Err(ArgsError::InvalidValue(flag.to_string(), value.to_string()))
+    }
+}
+
+pub fn parse_format(flag: &str, value: &str) -> Result<Format, ArgsError> {
+    match value {
+        "text" => Ok(Format::Text),
+        "json" => Ok(Format::Json),
+        "csv" => Ok(Format::Csv),
+        _ => Err(ArgsError::InvalidValue(flag.to_string(), value.to_string())),
+    }
+}
+
+pub fn parse_input_name(flag: &str, value: &str) -> Result<String, ArgsError> {
+    if value.is_empty() || value.contains(['/', '\\']) {
+        Err(ArgsError::InvalidValue(flag.to_string(), value.to_string()))
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+/// Answers are stored one per line in the ledger, so they can't be empty or
+/// contain whitespace.
+fn parse_guess(flag: &str, value: &str) -> Result<String, ArgsError> {
+    if value.is_empty() || value.contains(char::is_whitespace) {
+        Err(ArgsError::InvalidValue(flag.to_string(), value.to_string()))
+    } else {
+        Ok(value.to_string())
+    }
+}
+
+fn parse_command(arg: &str) -> Option<Command> {
+    match arg {
+        "bench" => Some(Command::Bench),
+        "verify" => Some(Command::Verify),
+        "new" => Some(Command::New),
+        "watch" => Some(Command::Watch),
+        "example" => Some(Command::Example),
+        "answer" => Some(Command::Answer),
+        "config" => Some(Command::Config),
+        "import-examples" => Some(Command::ImportExamples),
+        "gen" => Some(Command::Gen),
+        _ => None,
+    }
+}
+
+/// Parses the arguments following the program name, the settings of the
+/// config file being the defaults for the flags.
+///
+/// Usage: `aoc2023 [bench|verify|new|watch|example|answer] <all|days> [--year YEAR]
+/// [--part 1|2] [--input PATH|-] [--input-dir PATH] [--samples N] [--warmup N]
+/// [--save-baseline] [--threshold PERCENT]
+/// [--jobs N] [--timeout DURATION] [--format text|json|csv] [--watch PATH]
+/// [--input-name NAME] [--all-inputs] [--raw-input]
+/// [--guess ANSWER --feedback too-high|too-low|wrong|correct]`
+/// or `aoc2023 config show` or `aoc2023 import-examples <day> <file.html>`
+/// or `aoc2023 gen <day> [--seed N] [--size N] [--input-name NAME]`
+pub fn parse_args(args: &[String], config: &Config) -> Result<Args, ArgsError> {
+    let mut command = Command::Run;
+    let mut year = config.year.unwrap_or(DEFAULT_YEAR);
+    let mut input_dir = None;
+    let mut days = None;
+    let mut samples = config.samples.unwrap_or(50);
+    let mut warmup = config.warmup.unwrap_or(5);
+    let mut part = None;
+    let mut input = None;
+    let mut save_baseline = false;
+    let mut threshold = config.threshold.unwrap_or(10.0);
+    let mut jobs = config.jobs.unwrap_or(1);
+    let mut timeout = config.timeout;
+    let mut format = config.format.unwrap_or(Format::Text);
+    let mut watch = Vec::new();
+    let mut input_name = None;
+    let mut all_inputs = false;
+    let mut raw_input = false;
+    let mut guess = None;
+    let mut feedback = None;
+    let mut show = false;
+    let mut page = None;
+    let mut seed = None;
+    let mut size = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg.starts_with("--") {
+            match arg.as_str() {
+                "--year" => {
+                    year = parse_year(next_value(&mut args, arg)?).map_err(ArgsError::Year)?
+                }
+                "--samples" => samples = parse_count(arg, next_value(&mut args, arg)?)?,
+                "--warmup" => warmup = parse_value(arg, next_value(&mut args, arg)?)?,
+                "--part" => part = Some(parse_part(arg, next_value(&mut args, arg)?)?),
+                "--input" => input = Some(PathBuf::from(next_value(&mut args, arg)?)),
+                "--input-dir" => input_dir = Some(PathBuf::from(next_value(&mut args, arg)?)),
+                "--save-baseline" => save_baseline = true,
+                "--threshold" => threshold = parse_percent(arg, next_value(&mut args, arg)?)?,
+                "--jobs" => jobs = parse_count(arg, next_value(&mut args, arg)?)?,
+                "--timeout" => timeout = Some(parse_duration(arg, next_value(&mut args, arg)?)?),
+                "--format" => format = parse_format(arg, next_value(&mut args, arg)?)?,
+                "--watch" => watch.push(PathBuf::from(next_value(&mut args, arg)?)),
+                "--input-name" => {
+                    input_name = Some(parse_input_name(arg, next_value(&mut args, arg)?)?)
+                }
+                "--all-inputs" => all_inputs = true,
+                "--raw-input" => raw_input = true,
+                "--guess" => guess = Some(parse_guess(arg, next_value(&mut args, arg)?)?),
+                "--feedback" => feedback = Some(parse_value(arg, next_value(&mut args, arg)?)?),
+                "--seed" => seed = Some(parse_value(arg, next_value(&mut args, arg)?)?),
+                "--size" => size = Some(parse_count(arg, next_value(&mut args, arg)?)?),
+                _ => return Err(ArgsError::UnknownFlag(arg.clone())),
+            }
+        } else if let Some(subcommand) =
+            parse_command(arg).filter(|_| command == Command::Run && days.is_none())
+        {
+            command = subcommand;
+        } else if command == Command::Config && arg == "show" && !show {
+            show = true;
+        } else if command == Command::ImportExamples && days.is_some() && page.is_none() {
+            page = Some(PathBuf::from(arg));
+        } else if days.is_none() && command != Command::Config {
+            days = Some(if arg == "all" {
+                DaySelection::All
+            } else {
+                DaySelection::Days(parse_days(arg)?)
+            });
+        } else {
+            return Err(ArgsError::UnexpectedArgument(arg.clone()));
+        }
+    }
+
+    let days = match (days, command) {
+        (Some(days), _) => days,
+        (None, Command::Verify | Command::Example | Command::Config) => DaySelection::All,
+        (None, _) => return Err(ArgsError::MissingDays),
+    };
+    let conflicts = [
+        (input.is_some(), "--input"),
+        (input_name.is_some(), "--input-name"),
+        (all_inputs, "--all-inputs"),
+    ];
+    let mut given = conflicts.iter().filter(|(given, _)| *given);
+    if let (Some((_, first)), Some((_, second))) = (given.next(), given.next()) {
+        return Err(ArgsError::Conflict(first.to_string(), second.to_string()));
+    }
+    for (name, single_input) in [
+        ("watch", Command::Watch),
+        ("new", Command::New),
+        ("gen", Command::Gen),
+        ("bench", Command::Bench),
+    ] {
+        if all_inputs && command == single_input {
+            return Err(ArgsError::Conflict(
+                String::from("--all-inputs"),
+                name.to_string(),
+            ));
+        }
+    }
+
+    if command == Command::Gen && input.is_some() {
+        return Err(ArgsError::Conflict(
+            String::from("--input"),
+            String::from("gen"),
+        ));
+    }
+    // verify and example always run the puzzle inputs and the examples
+    for (name, own_inputs) in [("verify", Command::Verify), ("example", Command::Example)] {
+        if let Some((_, flag)) = conflicts.iter().find(|(given, _)| *given) {
+            if command == own_inputs {
+                return Err(ArgsError::Conflict(flag.to_string(), name.to_string()));
+            }
+        }
+    }
+
+    if command == Command::ImportExamples && page.is_none() {
+        return Err(ArgsError::Requires(
+            String::from("import-examples"),
+            String::from("<file.html>"),
+        ));
+    }
+    if command == Command::Config && !show {
+        return Err(ArgsError::Requires(
+            String::from("config"),
+            String::from("show"),
+        ));
+    }
+    // the configured input name is only a default for the day's own input,
+    // a generated input is only saved when asked to
+    let input_name = input_name.or_else(|| {
+        config.input_name.clone().filter(|_| {
+            input.is_none()
+                && !all_inputs
+                && !matches!(command, Command::Gen | Command::Verify | Command::Example)
+        })
+    });
+
+    let single_day = matches!(&days, DaySelection::Days(days) if days.len() == 1);
+    if input.is_some() && !single_day {
+        return Err(ArgsError::SingleDayOnly(String::from("--input")));
+    }
+    if command == Command::New && !single_day {
+        return Err(ArgsError::SingleDayOnly(String::from("new")));
+    }
+    if command == Command::Watch && !single_day {
+        return Err(ArgsError::SingleDayOnly(String::from("watch")));
+    }
+    if command == Command::Answer && !single_day {
+        return Err(ArgsError::SingleDayOnly(String::from("answer")));
+    }
+    if command == Command::ImportExamples && !single_day {
+        return Err(ArgsError::SingleDayOnly(String::from("import-examples")));
+    }
+    if command == Command::Gen && !single_day {
+        return Err(ArgsError::SingleDayOnly(String::from("gen")));
+    }
+    for (given, flag) in [
+        (guess.is_some(), "--guess"),
+        (feedback.is_some(), "--feedback"),
+    ] {
+        if given && command != Command::Answer {
+            return Err(ArgsError::Requires(
+                flag.to_string(),
+                String::from("answer"),
+            ));
+        }
+    }
+    for (given, flag) in [(seed.is_some(), "--seed"), (size.is_some(), "--size")] {
+        if given && command != Command::Gen {
+            return Err(ArgsError::Requires(flag.to_string(), String::from("gen")));
+        }
+    }
+    match (&guess, feedback) {
+        (Some(_), None) => {
+            return Err(ArgsError::Requires(
+                String::from("--guess"),
+                String::from("--feedback"),
+            ))
+        }
+        (None, Some(_)) => {
+            return Err(ArgsError::Requires(
+                String::from("--feedback"),
+                String::from("--guess"),
+            ))
+        }
+        (Some(_), Some(_)) if part.is_none() => {
+            return Err(ArgsError::Requires(
+                String::from("--guess"),
+                String::from("--part"),
+            ))
+        }
+        _ => {}
+    }
+    // stdin can only be read once, so there is nothing to re-run on
+    if command == Command::Watch && input.as_deref() == Some(Path::new("-")) {
+        return Err(ArgsError::InvalidValue(
+            String::from("--input"),
+            String::from("-"),
+        ));
+    }
+
+    Ok(Args {
+        command,
+        year,
+        input_dir,
+        days,
+        samples,
+        warmup,
+        part,
+        input,
+        save_baseline,
+        threshold,
+        jobs,
+        timeout,
+        format,
+        watch,
+        input_name,
+        all_inputs,
+        raw_input,
+        guess,
+        feedback,
+        page,
+        seed: seed.unwrap_or(1),
+        size: size.unwrap_or(10),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Args, ArgsError> {
+        parse_with(args, &Config::default())
+    }
+
+    fn parse_with(args: &str, config: &Config) -> Result<Args, ArgsError> {
+        let args: Vec<String> = args.split_whitespace().map(String::from).collect();
+        parse_args(&args, config)
+    }
+
+    #[test]
+    fn parses_run() {
+        let args = parse("3").unwrap();
+
+        assert_eq!(args.command, Command::Run);
+        assert_eq!(args.year, DEFAULT_YEAR);
+        assert_eq!(args.days, DaySelection::Days(vec![3]));
+        assert_eq!(args.part, None);
+    }
+
+    #[test]
+    fn parses_year() {
+        assert_eq!(parse("all --year 2022").unwrap().year, 2022);
+        assert!(matches!(
+            parse("3 --year 1999"),
+            Err(ArgsError::Year(DayError::UnknownYear(1999)))
+        ));
+        assert!(matches!(
+            parse("3 --year next"),
+            Err(ArgsError::Year(DayError::NotANumber(_)))
+        ));
+    }
+
+    #[test]
+    fn parses_part() {
+        assert_eq!(parse("3 --part 2").unwrap().part, Some(2));
+        assert!(matches!(
+            parse("3 --part 3"),
+            Err(ArgsError::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn parses_bench() {
+        let args = parse("bench all --samples 10 --warmup 0").unwrap();
+
+        assert_eq!(args.command, Command::Bench);
+        assert_eq!(args.days, DaySelection::All);
+        assert_eq!(args.samples, 10);
+        assert_eq!(args.warmup, 0);
+        assert!(!args.save_baseline);
+    }
+
+    #[test]
+    fn parses_verify() {
+        let args = parse("verify").unwrap();
+
+        assert_eq!(args.command, Command::Verify);
+        assert_eq!(args.days, DaySelection::All);
+        assert_eq!(
+            parse("verify 3-4").unwrap().days,
+            DaySelection::Days(vec![3, 4])
+        );
+    }
+
+    #[test]
+    fn parses_baseline_flags() {
+        let args = parse("bench 1-3 --save-baseline --threshold 2.5").unwrap();
+
+        assert!(args.save_baseline);
+        assert_eq!(args.threshold, 2.5);
+        for threshold in ["NaN", "inf", "-1"] {
+            assert!(matches!(
+                parse(&format!("bench 1 --threshold {}", threshold)),
+                Err(ArgsError::InvalidValue(_, _))
+            ));
+        }
+    }
+
+    #[test]
+    fn parses_jobs() {
+        assert_eq!(parse("all").unwrap().jobs, 1);
+        assert_eq!(parse("all --jobs 4").unwrap().jobs, 4);
+        assert!(matches!(
+            parse("all --jobs 0"),
+            Err(ArgsError::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn parses_timeout() {
+        assert_eq!(parse("all").unwrap().timeout, None);
+        assert_eq!(
+            parse("all --timeout 500ms").unwrap().timeout,
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(
+            parse("3 --timeout 1.5m").unwrap().timeout,
+            Some(Duration::from_secs(90))
+        );
+        assert_eq!(
+            parse("3 --timeout 2").unwrap().timeout,
+            Some(Duration::from_secs(2))
+        );
+        assert!(matches!(
+            parse("3 --timeout 0s"),
+            Err(ArgsError::InvalidValue(_, _))
+        ));
+        assert!(matches!(
+            parse("3 --timeout soon"),
+            Err(ArgsError::InvalidValue(_, _))
+        ));
+        assert!(matches!(
+            parse("3 --timeout 1e300"),
+            Err(ArgsError::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn parses_format() {
+        assert_eq!(parse("3").unwrap().format, Format::Text);
+        assert_eq!(parse("all --format json").unwrap().format, Format::Json);
+        assert_eq!(parse("bench 1 --format csv").unwrap().format, Format::Csv);
+        assert!(matches!(
+            parse("3 --format xml"),
+            Err(ArgsError::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn parses_input() {
+        assert_eq!(
+            parse("3 --input -").unwrap().input,
+            Some(PathBuf::from("-"))
+        );
+        assert!(matches!(
+            parse("1-3 --input foo.txt"),
+            Err(ArgsError::SingleDayOnly(_))
+        ));
+    }
+
+    #[test]
+    fn parses_named_inputs() {
+        let args = parse("1-3 --input-name alice").unwrap();
+        assert_eq!(args.input_name, Some(String::from("alice")));
+        assert!(!args.all_inputs);
+
+        assert!(parse("all --all-inputs").unwrap().all_inputs);
+        assert!(parse("3 --raw-input").unwrap().raw_input);
+        assert!(matches!(
+            parse("3 --input-name ../alice"),
+            Err(ArgsError::InvalidValue(_, _))
+        ));
+        assert!(matches!(
+            parse("3 --input foo.txt --all-inputs"),
+            Err(ArgsError::Conflict(_, _))
+        ));
+        assert!(matches!(
+            parse("watch 3 --all-inputs"),
+            Err(ArgsError::Conflict(_, _))
+        ));
+        assert!(matches!(
+            parse("bench all --all-inputs"),
+            Err(ArgsError::Conflict(_, _))
+        ));
+    }
+
+    #[test]
+    fn rejects_inputs_for_verify_and_example() {
+        for args in [
+            "verify --input a.txt",
+            "verify 3 --input-name alice",
+            "example 3 --all-inputs",
+        ] {
+            assert!(matches!(parse(args), Err(ArgsError::Conflict(_, _))));
+        }
+
+        let config = Config {
+            input_name: Some(String::from("alice")),
+            ..Config::default()
+        };
+        assert_eq!(parse_with("verify", &config).unwrap().input_name, None);
+        assert_eq!(parse_with("example 3", &config).unwrap().input_name, None);
+    }
+
+    #[test]
+    fn parses_new() {
+        let args = parse("new 10").unwrap();
+
+        assert_eq!(args.command, Command::New);
+        assert_eq!(args.days, DaySelection::Days(vec![10]));
+        assert!(matches!(parse("new all"), Err(ArgsError::SingleDayOnly(_))));
+    }
+
+    #[test]
+    fn parses_watch() {
+        let args = parse("watch 8 --watch notes.txt --input small.txt").unwrap();
+
+        assert_eq!(args.command, Command::Watch);
+        assert_eq!(args.watch, vec![PathBuf::from("notes.txt")]);
+        assert!(matches!(
+            parse("watch 1-3"),
+            Err(ArgsError::SingleDayOnly(_))
+        ));
+        assert!(matches!(
+            parse("watch 8 --input -"),
+            Err(ArgsError::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn parses_example() {
+        let args = parse("example 8").unwrap();
+
+        assert_eq!(args.command, Command::Example);
+        assert_eq!(args.days, DaySelection::Days(vec![8]));
+        assert_eq!(parse("example").unwrap().days, DaySelection::All);
+    }
+
+    #[test]
+    fn parses_answer() {
+        let args = parse("answer 5 --part 1 --guess 1234 --feedback too-high").unwrap();
+
+        assert_eq!(args.command, Command::Answer);
+        assert_eq!(args.guess, Some(String::from("1234")));
+        assert_eq!(args.feedback, Some(Feedback::TooHigh));
+        assert_eq!(parse("answer 5").unwrap().guess, None);
+        assert!(matches!(
+            parse("answer 1-3"),
+            Err(ArgsError::SingleDayOnly(_))
+        ));
+        assert!(matches!(
+            parse("answer 5 --part 1 --guess 1234"),
+            Err(ArgsError::Requires(_, _))
+        ));
+        assert!(matches!(
+            parse("answer 5 --guess 1234 --feedback wrong"),
+            Err(ArgsError::Requires(_, _))
+        ));
+        assert!(matches!(
+            parse("5 --part 1 --guess 1234 --feedback wrong"),
+            Err(ArgsError::Requires(_, _))
+        ));
+        assert!(matches!(
+            parse("answer 5 --part 1 --guess 1 --feedback high"),
+            Err(ArgsError::InvalidValue(_, _))
+        ));
+    }
+
+    #[test]
+    fn overrides_config() {
+        let config = Config {
+            year: Some(2022),
+            format: Some(Format::Json),
+            samples: Some(10),
+            input_name: Some(String::from("alice")),
+            ..Config::default()
+        };
+
+        let args = parse_with("bench 3", &config).unwrap();
+        assert_eq!(args.year, 2022);
+        assert_eq!(args.format, Format::Json);
+        assert_eq!(args.samples, 10);
+        assert_eq!(args.input_name, Some(String::from("alice")));
+
+        let args = parse_with("bench 3 --year 2023 --samples 3 --format text", &config).unwrap();
+        assert_eq!(args.year, 2023);
+        assert_eq!(args.format, Format::Text);
+        assert_eq!(args.samples, 3);
+
+        let args = parse_with("3 --input small.txt", &config).unwrap();
+        assert_eq!(args.input_name, None);
+        assert_eq!(
+            parse_with("all --all-inputs", &config).unwrap().input_name,
+            None
+        );
+    }
+
+    #[test]
+    fn parses_config_show() {
+        assert_eq!(parse("config show").unwrap().command, Command::Config);
+        assert!(matches!(parse("config"), Err(ArgsError::Requires(_, _))));
+        assert!(matches!(
+            parse("config 3"),
+            Err(ArgsError::UnexpectedArgument(_))
+        ));
+    }
+
+    #[test]
+    fn parses_import_examples() {
+        let args = parse("import-examples 10 day10.html").unwrap();
+
+        assert_eq!(args.command, Command::ImportExamples);
+        assert_eq!(args.days, DaySelection::Days(vec![10]));
+        assert_eq!(args.page, Some(PathBuf::from("day10.html")));
+        assert!(matches!(
+            parse("import-examples 10"),
+            Err(ArgsError::Requires(_, _))
+        ));
+        assert!(matches!(
+            parse("import-examples 1-3 days.html"),
+            Err(ArgsError::SingleDayOnly(_))
+        ));
+    }
+
+    #[test]
+    fn parses_gen() {
+        let args = parse("gen 7 --seed 42 --size 100").unwrap();
+
+        assert_eq!(args.command, Command::Gen);
+        assert_eq!(args.days, DaySelection::Days(vec![7]));
+        assert_eq!((args.seed, args.size), (42, 100));
+        assert_eq!(
+            (parse("gen 7").unwrap().seed, parse("gen 7").unwrap().size),
+            (1, 10)
+        );
+        assert!(matches!(parse("gen 1-3"), Err(ArgsError::SingleDayOnly(_))));
+        assert!(matches!(
+            parse("gen 7 --size 0"),
+            Err(ArgsError::InvalidValue(_, _))
+        ));
+        assert!(matches!(
+            parse("7 --seed 42"),
+            Err(ArgsError::Requires(_, _))
+        ));
+        assert!(matches!(
+            parse("gen 7 --input a.txt"),
+            Err(ArgsError::Conflict(_, _))
+        ));
+
+        let config = Config {
+            input_name: Some(String::from("alice")),
+            ..Config::default()
+        };
+        assert_eq!(parse_with("gen 7", &config).unwrap().input_name, None);
+    }
+
+    #[test]
+    fn rejects_bad_flags() {
+        assert!(matches!(parse(""), Err(ArgsError::MissingDays)));
+        assert!(matches!(
+            parse("1 --samples"),
+            Err(ArgsError::MissingValue(_))
+        ));
+        assert!(matches!(
+            parse("1 --samples 0"),
+            Err(ArgsError::InvalidValue(_, _))
+        ));
+        assert!(matches!(
+            parse("1 --fast 1"),
+            Err(ArgsError::UnknownFlag(_))
+        ));
+        assert!(matches!(
+            parse("1 2"),
+            Err(ArgsError::UnexpectedArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::HashSet;
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::cli::{
+    parse_count, parse_duration, parse_format, parse_input_name, parse_percent, parse_value,
+};
+use aoc2023::output::Format;
+use aoc2023::runner::parse_year;
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Defaults for the runner read from `aoc.toml`, flags given on the command
+/// line take precedence over them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    /// The file the settings were read from, `None` when there is none.
+    pub path: Option<PathBuf>,
+    pub year: Option<i32>,
+    pub input_dir: Option<PathBuf>,
+    pub format: Option<Format>,
+    pub jobs: Option<usize>,
+    pub timeout: Option<Duration>,
+    pub input_name: Option<String>,
+    pub samples: Option<usize>,
+    pub warmup: Option<usize>,
+    pub threshold: Option<f64>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ConfigError {
+    Syntax(PathBuf, usize, &'static str),
+    UnknownKey(PathBuf, String),
+    InvalidValue(PathBuf, String, String),
+    Io(PathBuf, io::ErrorKind),
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Syntax(path, line, message) => {
+                writeln!(f, "{}:{}: {}", path.display(), line, message)
+            }
+            ConfigError::UnknownKey(path, key) => {
+                writeln!(f, "{}: unknown setting `{}`", path.display(), key)
+            }
+            ConfigError::InvalidValue(path, key, value) => writeln!(
+                f,
+                "{}: invalid value `{}` for setting `{}`",
+                path.display(),
+                value,
+                key
+            ),
+            ConfigError::Io(path, kind) => {
+                writeln!(f, "failed to read \"{}\": {}", path.display(), kind)
+            }
+        }
+    }
+}
+
+/// A setting's line number, key and value.
+pub type Setting = (usize, String, String);
+
+/// Parses a quoted string at the start of `text`, returning its contents and
+/// what follows the closing quote.
+fn parse_string(text: &str) -> Option<(String, &str)> {
+    let mut result = String::new();
+    let mut chars = text.strip_prefix('"')?.char_indices();
+    while let Some((idx, ch)) = chars.next() {
+        match ch {
+            '"' => return Some((result, &text[idx + 2..])),
+            '\\' => result.push(match chars.next()?.1 {
+                'n' => '\n',
+                't' => '\t',
+                '"' => '"',
+                '\\' => '\\',
+                _ => return None,
+            }),
+            ch => result.push(ch),
+        }
+    }
+    None
+}
+
+/// Parses the subset of TOML the config needs: `key = value` lines where the
+/// value is a quoted string, a number or a boolean, `[table]` headers and `#`
+/// comments. Keys in a table are prefixed with its name, like `bench.samples`.
+/// Values are returned as text, the caller parses them like the flags.
+pub fn parse_toml(text: &str) -> Result<Vec<Setting>, (usize, &'static str)> {
+    let mut entries = Vec::new();
+    let mut seen = HashSet::new();
+    let mut table = String::new();
+
+    for (idx, line) in text.lines().enumerate() {
+        let number = idx + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            let (name, rest) = header
+                .split_once(']')
+                .ok_or((number, "unclosed table header"))?;
+            if !rest.trim().is_empty() && !rest.trim().starts_with('#') {
+                return Err((number, "unexpected text after table header"));
+            }
+            table = format!("{}.", name.trim());
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or((number, "expected `key = value`"))?;
+        let key = key.trim();
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err((number, "invalid key"));
+        }
+        let value = value.trim();
+        let (value, rest) = if value.starts_with('"') {
+            parse_string(value).ok_or((number, "unterminated string"))?
+        } else {
+            let end = value.find('#').unwrap_or(value.len());
+            let (token, rest) = value.split_at(end);
+            let token = token.trim();
+            if token != "true" && token != "false" && token.parse::<f64>().is_err() {
+                return Err((number, "expected a quoted string, a number or a boolean"));
+            }
+            (token.to_string(), rest)
+        };
+        if !rest.trim().is_empty() && !rest.trim().starts_with('#') {
+            return Err((number, "unexpected text after value"));
+        }
+
+        let key = format!("{}{}", table, key);
+        if !seen.insert(key.clone()) {
+            return Err((number, "duplicate key"));
+        }
+        entries.push((number, key, value));
+    }
+
+    Ok(entries)
+}
+
+/// Reads the settings of a config file. A relative `input_dir` is relative to
+/// the directory the file is in.
+pub fn parse_config(text: &str, path: &Path) -> Result<Config, ConfigError> {
+    let entries = parse_toml(text)
+        .map_err(|(line, message)| ConfigError::Syntax(path.into(), line, message))?;
+    let mut config = Config {
+        path: Some(path.to_path_buf()),
+        ..Config::default()
+    };
+
+    for (_, key, value) in entries {
+        let invalid = || ConfigError::InvalidValue(path.into(), key.clone(), value.clone());
+        match key.as_str() {
+            "year" => config.year = Some(parse_year(&value).map_err(|_| invalid())?),
+            "input_dir" => {
+                let dir = path.parent().unwrap_or(Path::new("."));
+                config.input_dir = Some(dir.join(&value));
+            }
+            "format" => config.format = Some(parse_format(&key, &value).map_err(|_| invalid())?),
+            "jobs" => config.jobs = Some(parse_count(&key, &value).map_err(|_| invalid())?),
+            "timeout" => {
+                config.timeout = Some(parse_duration(&key, &value).map_err(|_| invalid())?)
+            }
+            "input_name" => {
+                config.input_name = Some(parse_input_name(&key, &value).map_err(|_| invalid())?)
+            }
+            "bench.samples" => {
+                config.samples = Some(parse_count(&key, &value).map_err(|_| invalid())?)
+            }
+            "bench.warmup" => {
+                config.warmup = Some(parse_value(&key, &value).map_err(|_| invalid())?)
+            }
+            "bench.threshold" => {
+                config.threshold = Some(parse_percent(&key, &value).map_err(|_| invalid())?)
+            }
+            _ => return Err(ConfigError::UnknownKey(path.into(), key)),
+        }
+    }
+
+    Ok(config)
+}
+
+/// The closest `aoc.toml` in `start` or one of its parents.
+pub fn find_config(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join(CONFIG_FILE))
+        .find(|path| path.is_file())
+}
+
+/// Reads the config file found from the working directory upward, the
+/// default settings when there is none.
+pub fn load_config() -> Result<Config, ConfigError> {
+    let Some(path) = env::current_dir().ok().and_then(|cwd| find_config(&cwd)) else {
+        return Ok(Config::default());
+    };
+    let text = fs::read_to_string(&path).map_err(|e| ConfigError::Io(path.clone(), e.kind()))?;
+    parse_config(&text, &path)
+}
+
+/// Quotes a value as a TOML string.
+pub fn toml_string(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t");
+    format!("\"{}\"", escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"# runner defaults
+year = 2023
+input_dir = "puzzles" # shared inputs
+format = "json"
+timeout = "1.5s"
+
+[bench]
+samples = 20
+threshold = 2.5
+"#;
+
+    #[test]
+    fn parses_toml() {
+        let entries = parse_toml(EXAMPLE).unwrap();
+
+        assert_eq!(
+            entries[1],
+            (3, String::from("input_dir"), String::from("puzzles"))
+        );
+        assert_eq!(
+            entries[4],
+            (8, String::from("bench.samples"), String::from("20"))
+        );
+        assert_eq!(
+            parse_toml("name = \"a \\\"b\\\" # c\"").unwrap()[0].2,
+            "a \"b\" # c"
+        );
+        assert_eq!(
+            parse_toml("format = json"),
+            Err((1, "expected a quoted string, a number or a boolean"))
+        );
+        assert_eq!(parse_toml("a = 1\na = 2"), Err((2, "duplicate key")));
+        assert_eq!(parse_toml("[bench"), Err((1, "unclosed table header")));
+    }
+
+    #[test]
+    fn parses_config() {
+        let path = Path::new("/repo/aoc.toml");
+        let config = parse_config(EXAMPLE, path).unwrap();
+
+        assert_eq!(config.year, Some(2023));
+        assert_eq!(config.input_dir, Some(PathBuf::from("/repo/puzzles")));
+        assert_eq!(config.format, Some(Format::Json));
+        assert_eq!(config.timeout, Some(Duration::from_millis(1500)));
+        assert_eq!(config.samples, Some(20));
+        assert_eq!(config.threshold, Some(2.5));
+        assert_eq!(config.warmup, None);
+
+        assert!(matches!(
+            parse_config("samples = 20", path),
+            Err(ConfigError::UnknownKey(_, _))
+        ));
+        assert!(matches!(
+            parse_config("[bench]\nsamples = 0", path),
+            Err(ConfigError::InvalidValue(_, _, _))
+        ));
+        assert!(matches!(
+            parse_config("[bench]\nthreshold = nan", path),
+            Err(ConfigError::InvalidValue(_, _, _))
+        ));
+        assert!(matches!(
+            parse_config("timeout = 1e300", path),
+            Err(ConfigError::InvalidValue(_, _, _))
+        ));
+    }
+
+    #[test]
+    fn finds_config_upward() {
+        let root = env::temp_dir().join(format!("aoc2023-config-{}", std::process::id()));
+        let nested = root.join("src").join("y2023");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(root.join(CONFIG_FILE), "").unwrap();
+
+        let result = find_config(&nested);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(result, Some(root.join(CONFIG_FILE)));
+    }
+}
@@ -0,0 +1,115 @@
+use std::path::PathBuf;
+
+use crate::runner::{read_input, year_dir, DayError};
+
+/// A worked example kept in a day's examples file rather than in its module,
+/// see [`parse_examples`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportedExample {
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+const HEADER: &str = "--- example";
+
+/// Path of the day's examples file, `input/<year>/NN.examples`.
+pub fn examples_path(year: i32, day: i32) -> PathBuf {
+    year_dir(year).join(format!("{:02}.examples", day))
+}
+
+/// Parses an examples file. Every example starts with a header line like
+/// `--- example part1=142 part2=281`, leaving out the parts it has no answer
+/// for, and is followed by its input. Lines before the first header are
+/// ignored.
+pub fn parse_examples(text: &str) -> Vec<ImportedExample> {
+    let mut examples = Vec::new();
+    let mut current: Option<(ImportedExample, Vec<&str>)> = None;
+
+    let finish = |(mut example, lines): (ImportedExample, Vec<&str>)| {
+        if !lines.is_empty() {
+            example.input = lines.join("\n") + "\n";
+        }
+        example
+    };
+
+    for line in text.lines() {
+        let Some(answers) = line.strip_prefix(HEADER) else {
+            if let Some((_, lines)) = current.as_mut() {
+                lines.push(line);
+            }
+            continue;
+        };
+
+        examples.extend(current.take().map(finish));
+        let mut example = ImportedExample::default();
+        for token in answers.split_whitespace() {
+            match token.split_once('=') {
+                Some(("part1", answer)) => example.part1 = Some(answer.to_string()),
+                Some(("part2", answer)) => example.part2 = Some(answer.to_string()),
+                _ => {}
+            }
+        }
+        current = Some((example, Vec::new()));
+    }
+    examples.extend(current.map(finish));
+
+    examples
+}
+
+/// Writes examples in the format read by [`parse_examples`].
+pub fn render_examples(examples: &[ImportedExample]) -> String {
+    let mut result = String::new();
+    for example in examples {
+        result.push_str(HEADER);
+        for (part, answer) in [("part1", &example.part1), ("part2", &example.part2)] {
+            if let Some(answer) = answer {
+                result.push_str(&format!(" {}={}", part, answer));
+            }
+        }
+        result.push('\n');
+        result.push_str(&example.input);
+        if !example.input.is_empty() && !example.input.ends_with('\n') {
+            result.push('\n');
+        }
+    }
+    result
+}
+
+/// Reads the day's examples file, see [`examples_path`].
+pub fn read_examples(year: i32, day: i32) -> Result<Vec<ImportedExample>, DayError> {
+    read_input(&examples_path(year, day)).map(|text| parse_examples(&text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_rendered_examples() {
+        let examples = vec![
+            ImportedExample {
+                input: String::from("1abc2\n\npqr3stu8vwx\n"),
+                part1: Some(String::from("142")),
+                part2: None,
+            },
+            ImportedExample {
+                input: String::from("two1nine\n"),
+                part1: None,
+                part2: Some(String::from("281")),
+            },
+        ];
+
+        let text = render_examples(&examples);
+
+        assert_eq!(
+            text,
+            "--- example part1=142\n1abc2\n\npqr3stu8vwx\n--- example part2=281\ntwo1nine\n"
+        );
+        assert_eq!(parse_examples(&text), examples);
+        assert_eq!(
+            parse_examples("# notes\n--- example part2=x other\nab")[0].part2,
+            Some(String::from("x"))
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::time::Duration;
+
+use crate::runner::PuzzleError;
+
+/// A panic caught while running a part, with its message and where it was
+/// raised when the hook from [`record_panics`] is installed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => writeln!(f, "panicked at {}: {}", location, self.message),
+            None => writeln!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+/// Why a day or one of its parts didn't produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Puzzle(PuzzleError),
+    Panic(Panic),
+    Timeout(Duration),
+}
+
+impl Failure {
+    /// Short description of what went wrong, printed in place of an answer.
+    pub fn status(&self) -> &'static str {
+        match self {
+            Failure::Puzzle(_) => "invalid input",
+            Failure::Panic(_) => "panicked",
+            Failure::Timeout(_) => "TIMEOUT",
+        }
+    }
+
+    /// Status of a step in the structured output.
+    pub fn field(&self) -> &'static str {
+        match self {
+            Failure::Puzzle(_) => "invalid_input",
+            Failure::Panic(_) => "panicked",
+            Failure::Timeout(_) => "timeout",
+        }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Puzzle(e) => write!(f, "{}", e),
+            Failure::Panic(panic) => write!(f, "{}", panic),
+            Failure::Timeout(timeout) => writeln!(f, "timed out after {:.2?}", timeout),
+        }
+    }
+}
+
+impl From<PuzzleError> for Failure {
+    fn from(e: PuzzleError) -> Self {
+        Failure::Puzzle(e)
+    }
+}
+
+impl From<Panic> for Failure {
+    fn from(panic: Panic) -> Self {
+        Failure::Panic(panic)
+    }
+}
+
+thread_local! {
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+    /// Whether the thread is running a step inside [`catch`].
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Installs a panic hook that keeps the location of a panic for [`catch`]
+/// instead of printing it, so failures can be reported together. Panics
+/// outside of [`catch`] still go to the previous hook. Installing it again
+/// does nothing.
+pub fn record_panics() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) {
+                let location = info.location().map(|location| location.to_string());
+                LOCATION.with(|cell| *cell.borrow_mut() = location);
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("non-string panic payload")
+    }
+}
+
+/// Runs `f`, turning a panic into an error instead of unwinding further.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Panic> {
+    let outer = CATCHING.with(|catching| catching.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|catching| catching.set(outer));
+
+    result.map_err(|payload| Panic {
+        message: message(payload.as_ref()),
+        location: LOCATION.with(|cell| cell.borrow_mut().take()),
+    })
+}
+
+/// Runs a step that can fail on its input, a panic failing it too.
+pub fn catch_step<T>(f: impl FnOnce() -> Result<T, PuzzleError>) -> Result<T, Failure> {
+    match catch(f) {
+        Ok(result) => result.map_err(Failure::from),
+        Err(panic) => Err(panic.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catches_panics() {
+        record_panics();
+
+        assert_eq!(catch(|| 1 + 1), Ok(2));
+
+        let result = catch(|| -> i32 { panic!("Unexpected card {}", 'X') }).unwrap_err();
+        assert_eq!(result.message, "Unexpected card X");
+        assert!(result.location.unwrap().starts_with("src/failure.rs:"));
+        assert!(!CATCHING.with(Cell::get));
+    }
+
+    #[test]
+    fn catches_failing_steps() {
+        record_panics();
+        let error = PuzzleError::new(8, 1, "", "at least one instruction");
+
+        assert_eq!(catch_step(|| Ok(1)), Ok(1));
+        assert_eq!(
+            catch_step(|| -> Result<i32, _> { Err(error.clone()) }),
+            Err(Failure::Puzzle(error))
+        );
+        assert!(matches!(
+            catch_step(|| -> Result<i32, _> { panic!("no node") }),
+            Err(Failure::Panic(_))
+        ));
+    }
+}
@@ -0,0 +1,92 @@
+use std::ops::RangeInclusive;
+
+/// A small deterministic random number generator (SplitMix64), so the same
+/// seed generates the same input on every machine.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in the range, which must not be empty. Slightly biased for
+    /// ranges that don't divide 2^64, which doesn't matter for inputs.
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {}..={}", start, end);
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.next_u64() % len,
+            None => self.next_u64(),
+        }
+    }
+
+    /// An index into a collection of `len` items, `len` must not be zero.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..=len as u64 - 1) as usize
+    }
+
+    /// `true` with a probability of `percent` in a hundred.
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.range(0..=99) < percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.index(idx + 1));
+        }
+    }
+}
+
+/// A generated input with the answers it should produce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub part1: String,
+    pub part2: String,
+}
+
+/// Generates an input of a day, `size` scaling how large it is, like the
+/// number of lines.
+pub type Generator = fn(&mut Rng, usize) -> Generated;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeats_sequences_per_seed() {
+        let sequence = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(sequence(7), sequence(7));
+        assert_ne!(sequence(7), sequence(8));
+    }
+
+    #[test]
+    fn stays_in_range() {
+        let mut rng = Rng::new(1);
+        let mut items = [1, 2, 3, 4, 5];
+        rng.shuffle(&mut items);
+        items.sort();
+
+        assert!((0..100).all(|_| (3..=5).contains(&rng.range(3..=5))));
+        assert_eq!(rng.range(9..=9), 9);
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+    }
+}
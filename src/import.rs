@@ -0,0 +1,196 @@
+use crate::examples::ImportedExample;
+
+/// The text between each `start` and the following `end`, in order.
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Vec<(usize, &'a str)> {
+    let mut result = Vec::new();
+    let mut offset = 0;
+    while let Some(found) = text[offset..].find(start) {
+        let content = offset + found + start.len();
+        let Some(length) = text[content..].find(end) else {
+            break;
+        };
+        result.push((content, &text[content..content + length]));
+        offset = content + length + end.len();
+    }
+    result
+}
+
+/// Removes the tags from a fragment of HTML, like the `<em>` highlighting
+/// part of an example.
+fn strip_tags(html: &str) -> String {
+    let mut result = String::new();
+    let mut in_tag = false;
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            ch if !in_tag => result.push(ch),
+            _ => {}
+        }
+    }
+    result
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let ch = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                entity => {
+                    let code = match entity.strip_prefix("#x") {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => entity.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((ch, end))
+        });
+        match decoded {
+            Some((ch, end)) => {
+                result.push(ch);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+fn text_of(html: &str) -> String {
+    decode_entities(&strip_tags(html))
+}
+
+/// The last emphasised code in an article, which is where the puzzle text
+/// gives the example's answer.
+fn last_answer(article: &str) -> Option<String> {
+    let mut answers = between(article, "<code><em>", "</em></code>");
+    answers.extend(between(article, "<em><code>", "</code></em>"));
+    answers
+        .into_iter()
+        .max_by_key(|(position, _)| *position)
+        .map(|(_, answer)| text_of(answer).trim().to_string())
+}
+
+/// Pulls the examples out of a saved puzzle page. Each part's description is
+/// an `<article>`, its example being its first `<pre><code>` block and its
+/// answer the last emphasised `<code>`. A part without an example of its own
+/// gives the answer for the example before it.
+pub fn extract_examples(html: &str) -> Vec<ImportedExample> {
+    let mut articles: Vec<&str> = between(html, "<article", "</article>")
+        .into_iter()
+        .map(|(_, article)| article)
+        .collect();
+    if articles.is_empty() {
+        articles.push(html);
+    }
+
+    let mut examples: Vec<ImportedExample> = Vec::new();
+    for (idx, article) in articles.into_iter().take(2).enumerate() {
+        let input = between(article, "<pre><code>", "</code></pre>")
+            .first()
+            .map(|(_, block)| text_of(block));
+        let known = match &input {
+            Some(input) => examples.iter().position(|example| &example.input == input),
+            None => examples.len().checked_sub(1),
+        };
+        let example = match (known, input) {
+            (Some(known), _) => &mut examples[known],
+            (None, Some(input)) => {
+                examples.push(ImportedExample {
+                    input,
+                    ..ImportedExample::default()
+                });
+                examples.last_mut().unwrap()
+            }
+            (None, None) => continue,
+        };
+
+        let answer = last_answer(article);
+        if idx == 0 {
+            example.part1 = answer;
+        } else {
+            example.part2 = answer;
+        }
+    }
+
+    examples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d&lt;4e5f
+</code></pre>
+<p>In this example, the calibration values are <code>12</code> and <code>38</code>.
+Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54951</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code><em>two</em>1nine
+eightwothree
+</code></pre>
+<p>Adding these together produces <em><code>281</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn extracts_examples() {
+        let examples = extract_examples(PAGE);
+
+        assert_eq!(
+            examples,
+            vec![
+                ImportedExample {
+                    input: String::from("1abc2\npqr3stu8vwx\na1b2c3d<4e5f\n"),
+                    part1: Some(String::from("142")),
+                    part2: None,
+                },
+                ImportedExample {
+                    input: String::from("two1nine\neightwothree\n"),
+                    part1: None,
+                    part2: Some(String::from("281")),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn answers_part_two_on_the_same_example() {
+        let page = PAGE.replace(
+            "<pre><code><em>two</em>1nine\neightwothree\n</code></pre>",
+            "",
+        );
+        let examples = extract_examples(&page);
+
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].part2, Some(String::from("281")));
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode_entities("a &amp;&lt;b&gt; &#35; &#x41; & c"),
+            "a &<b> # A & c"
+        );
+    }
+}
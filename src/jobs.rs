@@ -0,0 +1,264 @@
+use std::collections::VecDeque;
+use std::sync::{mpsc, Arc, Condvar, Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::runner::{Allocations, DayRun, Parsed, PartFn, PartRun, PuzzleError, Solution};
+
+use crate::alloc::track;
+use crate::failure::{catch_step, Failure};
+
+enum Task {
+    Parse(usize),
+    Part(usize, i32, PartFn),
+}
+
+struct Queue {
+    tasks: VecDeque<Task>,
+    /// Tasks queued or still running, a running parse can queue more tasks.
+    pending: usize,
+}
+
+#[derive(Default)]
+struct Slot {
+    parse: Option<Result<(Duration, Option<Allocations>), Failure>>,
+    parts: Vec<PartRun<Failure>>,
+}
+
+struct Step<T> {
+    result: Result<T, Failure>,
+    elapsed: Duration,
+    allocations: Option<Allocations>,
+}
+
+/// Runs a step on the current thread, timing it, counting its allocations
+/// and catching its panic.
+/// With a timeout the step runs on a thread of its own instead and is given
+/// up on once the time runs out. Threads can't be stopped, so a step that
+/// timed out keeps running in the background and its result is dropped.
+fn run_step<T: Send + 'static>(
+    timeout: Option<Duration>,
+    step: impl FnOnce() -> Result<T, PuzzleError> + Send + 'static,
+) -> Step<T> {
+    let timed = move || {
+        let start = Instant::now();
+        let (result, allocations) = track(|| catch_step(step));
+        Step {
+            result,
+            elapsed: start.elapsed(),
+            allocations,
+        }
+    };
+
+    let Some(timeout) = timeout else {
+        return timed();
+    };
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = sender.send(timed());
+    });
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        // the step catches its panics, so the sender is only dropped early
+        // if the thread is torn down, which can't be told apart from hanging
+        Err(_) => Step {
+            result: Err(Failure::Timeout(timeout)),
+            elapsed: timeout,
+            allocations: None,
+        },
+    }
+}
+
+/// Runs each day on `jobs` worker threads and returns the runs in the order
+/// the days were given. A day is parsed once, after which its parts are
+/// queued separately so they can run concurrently too. A panic or timeout
+/// only fails the step it happened in.
+pub fn run_days(
+    jobs: usize,
+    days: &[(Solution, String)],
+    selected: Option<i32>,
+    timeout: Option<Duration>,
+) -> Vec<Result<DayRun<Failure>, Failure>> {
+    let queue = Mutex::new(Queue {
+        tasks: (0..days.len()).map(Task::Parse).collect(),
+        pending: days.len(),
+    });
+    let ready = Condvar::new();
+    let parsed: Vec<OnceLock<Arc<Parsed>>> = days.iter().map(|_| OnceLock::new()).collect();
+    let slots: Vec<Mutex<Slot>> = days.iter().map(|_| Mutex::default()).collect();
+
+    let run_task = |task: Task| -> Vec<Task> {
+        match task {
+            Task::Parse(idx) => {
+                let (solution, input) = &days[idx];
+                let (parse, input) = (solution.parse, input.clone());
+                let step = run_step(timeout, move || parse(&input));
+
+                let mut slot = slots[idx].lock().unwrap();
+                slot.parse = Some(step.result.map(|result| {
+                    let _ = parsed[idx].set(Arc::new(result));
+                    (step.elapsed, step.allocations)
+                }));
+                match slot.parse {
+                    Some(Ok(_)) => solution
+                        .parts(selected)
+                        .into_iter()
+                        .map(|(part, part_fn)| Task::Part(idx, part, part_fn))
+                        .collect(),
+                    _ => Vec::new(),
+                }
+            }
+            Task::Part(idx, part, part_fn) => {
+                let input = Arc::clone(parsed[idx].get().expect("part queued before its parse"));
+                let step = run_step(timeout, move || part_fn(&input));
+
+                slots[idx].lock().unwrap().parts.push(PartRun {
+                    part,
+                    answer: step.result,
+                    elapsed: step.elapsed,
+                    allocations: step.allocations,
+                });
+                Vec::new()
+            }
+        }
+    };
+
+    let worker = || loop {
+        let task = {
+            let mut queue = queue.lock().unwrap();
+            loop {
+                if let Some(task) = queue.tasks.pop_front() {
+                    break task;
+                }
+                if queue.pending == 0 {
+                    return;
+                }
+                queue = ready.wait(queue).unwrap();
+            }
+        };
+
+        let queued = run_task(task);
+
+        let mut queue = queue.lock().unwrap();
+        queue.pending += queued.len();
+        queue.pending -= 1;
+        // parts go first so days that started finish before new ones start
+        for task in queued.into_iter().rev() {
+            queue.tasks.push_front(task);
+        }
+        ready.notify_all();
+    };
+
+    // a day has at most its two parts to run at once
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(2 * days.len()) {
+            scope.spawn(worker);
+        }
+    });
+
+    slots
+        .into_iter()
+        .map(|slot| {
+            let slot = slot.into_inner().unwrap();
+            let (parse_time, parse_allocations) = slot.parse.expect("every day is parsed")?;
+            let mut parts = slot.parts;
+            parts.sort_by_key(|part_run| part_run.part);
+            Ok(DayRun {
+                parse_time,
+                parse_allocations,
+                parts,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2023::DAYS;
+
+    fn answers<E: std::fmt::Debug>(runs: Vec<Result<DayRun<E>, E>>) -> Vec<Vec<(i32, String)>> {
+        runs.into_iter()
+            .map(|run| {
+                run.unwrap()
+                    .parts
+                    .into_iter()
+                    .map(|part_run| (part_run.part, part_run.answer.unwrap()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn runs_days_in_order() {
+        let days: Vec<_> = DAYS
+            .iter()
+            .map(|entry| (entry.solution, entry.examples[0].input.to_string()))
+            .collect();
+        let sequential = run_days(1, &days, None, None);
+
+        let result = answers(run_days(4, &days, None, None));
+
+        assert_eq!(result, answers(sequential));
+        assert_eq!(
+            result[1],
+            vec![(1, String::from("8")), (2, String::from("2286"))]
+        );
+    }
+
+    #[test]
+    fn reports_parse_errors_per_day() {
+        let solution = DAYS[8].solution;
+        let days = vec![
+            (solution, String::from("1 2 x\n")),
+            (solution, String::from("0 3 6 9 12 15\n")),
+        ];
+
+        let result = run_days(2, &days, Some(2), None);
+
+        assert!(result[0].is_err());
+        let parts = &result[1].as_ref().unwrap().parts;
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].answer, Ok(String::from("-3")));
+    }
+
+    #[test]
+    fn isolates_panics() {
+        crate::failure::record_panics();
+        let days = vec![
+            // the only card wins copies of cards past the end
+            (DAYS[3].solution, String::from("Card 1: 1 2 | 1 2\n")),
+            (DAYS[8].solution, String::from("0 3 6 9 12 15\n")),
+        ];
+
+        let result = run_days(2, &days, None, None);
+
+        let parts = &result[0].as_ref().unwrap().parts;
+        assert_eq!(parts[0].answer, Ok(String::from("2")));
+        assert!(matches!(parts[1].answer, Err(Failure::Panic(_))));
+        assert_eq!(
+            answers(vec![result.into_iter().nth(1).unwrap()]),
+            vec![vec![(1, String::from("18")), (2, String::from("-3"))]]
+        );
+    }
+
+    #[test]
+    fn times_out_hanging_parts() {
+        // AAA never reaches a node ending in Z, so both parts loop forever
+        let days = vec![
+            (
+                DAYS[7].solution,
+                String::from("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\n"),
+            ),
+            (DAYS[8].solution, String::from("0 3 6 9 12 15\n")),
+        ];
+        let timeout = Duration::from_millis(50);
+
+        let result = run_days(2, &days, None, Some(timeout));
+
+        let parts = &result[0].as_ref().unwrap().parts;
+        assert_eq!(parts[0].answer, Err(Failure::Timeout(timeout)));
+        assert_eq!(parts[1].answer, Err(Failure::Timeout(timeout)));
+        assert_eq!(result[1].as_ref().unwrap().parts.len(), 2);
+    }
+}
@@ -0,0 +1,219 @@
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::runner::year_dir;
+
+/// What the puzzle page said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feedback {
+    TooHigh,
+    TooLow,
+    Wrong,
+    Correct,
+}
+
+impl Display for Feedback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Feedback::TooHigh => write!(f, "too-high"),
+            Feedback::TooLow => write!(f, "too-low"),
+            Feedback::Wrong => write!(f, "wrong"),
+            Feedback::Correct => write!(f, "correct"),
+        }
+    }
+}
+
+impl FromStr for Feedback {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "too-high" => Ok(Feedback::TooHigh),
+            "too-low" => Ok(Feedback::TooLow),
+            "wrong" => Ok(Feedback::Wrong),
+            "correct" => Ok(Feedback::Correct),
+            _ => Err(()),
+        }
+    }
+}
+
+/// An answer that was submitted for a part and the feedback it got.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: i32,
+    pub part: i32,
+    pub answer: String,
+    pub feedback: Feedback,
+}
+
+/// Why a computed answer shouldn't be submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Warning {
+    Submitted(Feedback),
+    NotCorrect(String),
+    AtLeastTooHigh(String),
+    AtMostTooLow(String),
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Warning::Submitted(feedback) => {
+                write!(f, "was already submitted and marked {}", feedback)
+            }
+            Warning::NotCorrect(correct) => {
+                write!(f, "differs from the correct answer {}", correct)
+            }
+            Warning::AtLeastTooHigh(bound) => {
+                write!(f, "is not below {}, which was too high", bound)
+            }
+            Warning::AtMostTooLow(bound) => {
+                write!(f, "is not above {}, which was too low", bound)
+            }
+        }
+    }
+}
+
+pub fn ledger_path(year: i32) -> PathBuf {
+    year_dir(year).join("ledger.txt")
+}
+
+/// Parses lines of the form `day part feedback answer`, skipping blank lines,
+/// `#` comments and lines that don't match.
+pub fn parse_ledger(text: &str) -> Vec<Entry> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let day = fields.next()?.parse().ok()?;
+            let part = fields.next()?.parse().ok()?;
+            let feedback = fields.next()?.parse().ok()?;
+            let answer = fields.next()?.to_string();
+            Some(Entry {
+                day,
+                part,
+                answer,
+                feedback,
+            })
+        })
+        .collect()
+}
+
+/// Reads the event's ledger, which is empty until an answer is recorded.
+pub fn read_ledger(year: i32) -> Vec<Entry> {
+    fs::read_to_string(ledger_path(year))
+        .map(|text| parse_ledger(&text))
+        .unwrap_or_default()
+}
+
+pub fn append_entry(path: &Path, entry: &Entry) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(
+        file,
+        "{} {} {} {}",
+        entry.day, entry.part, entry.feedback, entry.answer
+    )
+}
+
+/// Checks an answer against what was already submitted for the part. The
+/// bounds only apply to numeric answers.
+pub fn check_answer(ledger: &[Entry], day: i32, part: i32, answer: &str) -> Option<Warning> {
+    let entries: Vec<&Entry> = ledger
+        .iter()
+        .filter(|entry| entry.day == day && entry.part == part)
+        .collect();
+
+    if let Some(entry) = entries.iter().find(|entry| entry.answer == answer) {
+        return match entry.feedback {
+            Feedback::Correct => None,
+            feedback => Some(Warning::Submitted(feedback)),
+        };
+    }
+    if let Some(correct) = entries
+        .iter()
+        .find(|entry| entry.feedback == Feedback::Correct)
+    {
+        return Some(Warning::NotCorrect(correct.answer.clone()));
+    }
+
+    let value: i128 = answer.parse().ok()?;
+    let bound = |feedback: Feedback| {
+        entries
+            .iter()
+            .filter(move |entry| entry.feedback == feedback)
+            .filter_map(|entry| Some((entry.answer.parse::<i128>().ok()?, &entry.answer)))
+    };
+    if let Some((_, lowest)) = bound(Feedback::TooHigh)
+        .filter(|(high, _)| value >= *high)
+        .min()
+    {
+        return Some(Warning::AtLeastTooHigh(lowest.to_string()));
+    }
+    if let Some((_, highest)) = bound(Feedback::TooLow)
+        .filter(|(low, _)| value <= *low)
+        .max()
+    {
+        return Some(Warning::AtMostTooLow(highest.to_string()));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"# day part feedback answer
+5 1 too-high 500
+5 1 too-high 800
+5 1 too-low 100
+5 2 wrong abc
+5 2 correct xyz
+bad line
+"#;
+
+    #[test]
+    fn parses_ledger() {
+        let ledger = parse_ledger(EXAMPLE);
+
+        assert_eq!(ledger.len(), 5);
+        assert_eq!(
+            ledger[3],
+            Entry {
+                day: 5,
+                part: 2,
+                answer: String::from("abc"),
+                feedback: Feedback::Wrong,
+            }
+        );
+    }
+
+    #[test]
+    fn warns_about_known_answers() {
+        let ledger = parse_ledger(EXAMPLE);
+        let check = |part, answer| check_answer(&ledger, 5, part, answer);
+
+        assert_eq!(check(1, "800"), Some(Warning::Submitted(Feedback::TooHigh)));
+        assert_eq!(
+            check(1, "650"),
+            Some(Warning::AtLeastTooHigh(String::from("500")))
+        );
+        assert_eq!(
+            check(1, "99"),
+            Some(Warning::AtMostTooLow(String::from("100")))
+        );
+        assert_eq!(check(1, "250"), None);
+        assert_eq!(check(2, "xyz"), None);
+        assert_eq!(
+            check(2, "abd"),
+            Some(Warning::NotCorrect(String::from("xyz")))
+        );
+        assert_eq!(check_answer(&ledger, 6, 1, "1"), None);
+    }
+}
@@ -0,0 +1,89 @@
+//! Advent of Code solutions, starting with 2023.
+//!
+//! Every event is a public `yYYYY` module, in which every day is a public
+//! module exposing its `DayNN` type. That type implements one of the
+//! [`Solve`], [`TrySolve`] or [`ParseSolve`] traits. [`YEARS`] lists the
+//! implemented days of each event and [`solve_year`] runs a part of any of
+//! them by number, [`solve`] one of [`DEFAULT_YEAR`].
+//!
+//! The rest of the runner lives here too so it can be used without the
+//! binary: [`run_days`] runs days on a worker pool, isolating panics and
+//! timeouts as a [`Failure`], [`bench`](mod@bench) measures them, [`check`] compares
+//! answers with the known ones and [`output`] formats the results.
+
+use std::fmt::Display;
+
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod examples;
+pub mod failure;
+pub mod generator;
+pub mod import;
+pub mod jobs;
+pub mod ledger;
+pub mod output;
+pub mod runner;
+pub mod table;
+
+pub use answers::{check, Answers, Verdict};
+pub use failure::{catch, catch_step, Failure};
+pub use jobs::run_days;
+pub use runner::{parse_day, parse_year, Day, ParseSolve, PuzzleError, Solve, TrySolve, Year};
+
+use runner::{find_day, normalize, register_years};
+
+register_years! {
+    2023 => y2023;
+}
+
+/// The event the runner works on unless another year is given.
+pub const DEFAULT_YEAR: i32 = 2023;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    NotImplemented(i32, i32),
+    NotAPart(i32),
+    Puzzle(PuzzleError),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::NotImplemented(year, day) => {
+                writeln!(f, "day {:02} of {} is not implemented", day, year)
+            }
+            SolveError::NotAPart(part) => {
+                writeln!(f, "part {} does not exist, should be 1 or 2", part)
+            }
+            SolveError::Puzzle(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<PuzzleError> for SolveError {
+    fn from(e: PuzzleError) -> Self {
+        SolveError::Puzzle(e)
+    }
+}
+
+/// Solves a part of a day of [`DEFAULT_YEAR`] for the given input, returning
+/// the answer as it would be submitted. See [`solve_year`] for other events.
+pub fn solve(day: i32, part: i32, input: &str) -> Result<String, SolveError> {
+    solve_year(DEFAULT_YEAR, day, part, input)
+}
+
+/// Solves a part of a day of an event for the given input, returning the
+/// answer as it would be submitted. The input is normalized first, see
+/// [`normalize`].
+pub fn solve_year(year: i32, day: i32, part: i32, input: &str) -> Result<String, SolveError> {
+    let entry = find_day(YEARS, year, day).ok_or(SolveError::NotImplemented(year, day))?;
+    let part_fn = match part {
+        1 => entry.solution.part1,
+        2 => entry.solution.part2,
+        _ => return Err(SolveError::NotAPart(part)),
+    };
+
+    let parsed = (entry.solution.parse)(&normalize(input))?;
+    Ok(part_fn(&parsed)?)
+}
@@ -1,52 +1,1100 @@
 use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
 
-use crate::runner::{parse_day, read_day_input, Solve};
-mod runner;
+use crate::cli::{parse_args, Args, Command, DaySelection};
+use crate::config::{load_config, toml_string, Config};
+use crate::scaffold::new_day;
+use crate::watch::{watch, POLL_INTERVAL};
+use aoc2023::alloc::format_bytes;
+use aoc2023::answers::{check, read_answers, with_verdict, Answers, Verdict};
+use aoc2023::bench::{
+    baseline_path, change_percent, measure, read_baseline, step_name, write_baseline,
+};
+use aoc2023::examples::{examples_path, read_examples, render_examples};
+use aoc2023::failure::{catch_step, record_panics, Failure};
+use aoc2023::generator::Rng;
+use aoc2023::import::extract_examples;
+use aoc2023::jobs::run_days;
+use aoc2023::ledger::{append_entry, check_answer, ledger_path, read_ledger, Entry, Warning};
+use aoc2023::output::{bench_record, print_records, run_record, Format, Record};
+use aoc2023::runner::{
+    find_day, find_year, input_dir, input_names, input_path, named_input_path, normalize,
+    read_day_input, read_input, uses_legacy_inputs, Allocations, DayError,
+};
+use aoc2023::table::print_table;
+use aoc2023::YEARS;
+mod cli;
+mod config;
+mod scaffold;
+mod watch;
 
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
+/// Exit code used when an input doesn't match the puzzle format.
+const PUZZLE_ERROR_EXIT: u8 = 3;
+/// Exit code used when a part panicked, the same as an uncaught panic.
+const PANIC_EXIT: u8 = 101;
+/// Exit code used when a part ran out of time, the same as `timeout(1)`.
+const TIMEOUT_EXIT: u8 = 124;
 
-fn main() {
-    let args: Vec<String> = env::args().collect();
+/// The input given with `--input`, the named one given with `--input-name`
+/// or the day's input file otherwise.
+fn input_file(day: i32, args: &Args) -> PathBuf {
+    match (&args.input, &args.input_name) {
+        (Some(path), _) => path.clone(),
+        (None, Some(name)) => named_input_path(args.year, day, name),
+        (None, None) => input_path(args.year, day),
+    }
+}
+
+/// Normalizes the input unless `--raw-input` is given, see [`normalize`].
+fn prepare_input(input: String, args: &Args) -> String {
+    if args.raw_input {
+        input
+    } else {
+        normalize(&input)
+    }
+}
+
+fn load_input(day: i32, args: &Args) -> Result<String, DayError> {
+    read_input(&input_file(day, args)).map(|input| prepare_input(input, args))
+}
+
+/// The inputs to run a day on with their names, `None` being the default
+/// input. With `--all-inputs` that is the day's input file when there is one
+/// followed by all of its named inputs.
+fn load_inputs(day: i32, args: &Args) -> Vec<(Option<String>, Result<String, DayError>)> {
+    if !args.all_inputs {
+        return vec![(args.input_name.clone(), load_input(day, args))];
+    }
+
+    let mut names: Vec<Option<String>> =
+        input_names(args.year, day).into_iter().map(Some).collect();
+    if input_path(args.year, day).exists() || names.is_empty() {
+        names.insert(0, None);
+    }
+    names
+        .into_iter()
+        .map(|name| {
+            let input = read_day_input(args.year, day, name.as_deref());
+            (name, input.map(|input| prepare_input(input, args)))
+        })
+        .collect()
+}
 
-    if args.len() != 2 {
-        eprintln!("error: missing day to run.");
-        return;
+/// Warns when an answer was already submitted without being correct or can't
+/// be right going by the feedback on earlier ones.
+fn warn_known(ledger: &[Entry], day: i32, part: i32, answer: &str) {
+    if let Some(warning) = check_answer(ledger, day, part, answer) {
+        eprintln!(
+            "warning: day {:02} part {}: {} {}",
+            day, part, answer, warning
+        );
     }
+}
 
-    let day = match parse_day(&args[1]) {
-        Ok(day) => day,
+fn run_single(day: i32, args: &Args, answers: Option<&Answers>, ledger: &[Entry]) -> ExitCode {
+    let Some(entry) = find_day(YEARS, args.year, day) else {
+        eprintln!("Not implemented yet");
+        return ExitCode::FAILURE;
+    };
+    let input = match load_input(day, args) {
+        Ok(input) => input,
         Err(e) => {
-            eprintln!("Failed to parse day: {}", e);
-            return;
+            eprint!("error: {}", e);
+            return ExitCode::FAILURE;
         }
     };
-    let input = read_day_input(day);
 
-    let (p1, p2) = match day {
-        1 => day01::Day01::solve(&input),
-        2 => day02::Day02::solve(&input),
-        3 => day03::Day03::solve(&input),
-        4 => day04::Day04::solve(&input),
-        5 => day05::Day05::solve(&input),
-        6 => day06::Day06::solve(&input),
-        7 => day07::Day07::solve(&input),
-        8 => day08::Day08::solve(&input),
-        9 => day09::Day09::solve(&input),
-        _ => {
-            eprintln!("Not implemented yet");
-            return;
+    println!("Running day: {:02}", day);
+    record_panics();
+    let run = run_days(
+        args.jobs,
+        &[(entry.solution, input)],
+        args.part,
+        args.timeout,
+    )
+    .pop()
+    .expect("one run per day");
+    let run = match run {
+        Ok(run) => run,
+        Err(failure) => {
+            eprint!("error: {}", failure);
+            return ExitCode::from(failure_exit(&failure));
         }
     };
+    println!("Parse: {:.2?}", run.parse_time);
+    // a part that failed doesn't stop the other one from being reported
+    let mut exit = 0;
+    for part_run in run.parts {
+        match part_run.answer {
+            Ok(answer) => {
+                warn_known(ledger, day, part_run.part, &answer);
+                let answer = with_verdict(answers, day, part_run.part, answer);
+                println!(
+                    "Part {}: {} ({:.2?})",
+                    part_run.part, answer, part_run.elapsed
+                );
+                if let Some(allocations) = part_run.allocations {
+                    println!(
+                        "  {} allocations, {} allocated, {} peak",
+                        allocations.count,
+                        format_bytes(allocations.bytes),
+                        format_bytes(allocations.peak)
+                    );
+                }
+            }
+            Err(failure) => {
+                println!("Part {}: {}", part_run.part, failure.status());
+                eprint!("error: {}", failure);
+                exit = exit.max(failure_exit(&failure));
+            }
+        }
+    }
+    ExitCode::from(exit)
+}
 
-    println!("Running day: {:02}", day);
-    println!("Part 1: {}", p1);
-    println!("Part 2: {}", p2);
+fn failure_exit(failure: &Failure) -> u8 {
+    match failure {
+        Failure::Puzzle(_) => PUZZLE_ERROR_EXIT,
+        Failure::Panic(_) => PANIC_EXIT,
+        Failure::Timeout(_) => TIMEOUT_EXIT,
+    }
+}
+
+/// Prints what went wrong for each day and step that failed while running
+/// several days, exiting with the highest exit code among them.
+fn report_failures(failures: &[(i32, String, Failure)]) -> ExitCode {
+    if failures.is_empty() {
+        return ExitCode::SUCCESS;
+    }
+
+    eprintln!("{} step(s) failed:", failures.len());
+    for (day, step, failure) in failures {
+        eprint!("  day {:02} {}: {}", day, step, failure);
+    }
+
+    let code = failures
+        .iter()
+        .map(|(_, _, failure)| failure_exit(failure))
+        .max()
+        .unwrap_or(PUZZLE_ERROR_EXIT);
+    ExitCode::from(code)
+}
+
+/// The day, followed by the input's name when it isn't the default one.
+fn day_cell(day: i32, input: Option<&str>) -> String {
+    match input {
+        Some(input) => format!("{:02} {}", day, input),
+        None => format!("{:02}", day),
+    }
+}
+
+/// Names the step along with the input it ran on, for the failure summary.
+fn step_label(input: Option<&str>, step: &str) -> String {
+    match input {
+        Some(input) => format!("{} on {}", step, input),
+        None => step.to_string(),
+    }
+}
+
+fn allocation_row(day: &str, step: &str, allocations: Allocations) -> [String; 5] {
+    [
+        day.to_string(),
+        step.to_string(),
+        allocations.count.to_string(),
+        format_bytes(allocations.bytes),
+        format_bytes(allocations.peak),
+    ]
+}
+
+fn run_many(days: &[i32], args: &Args, answers: Option<&Answers>, ledger: &[Entry]) -> ExitCode {
+    let mut rows = vec![[
+        String::from("Day"),
+        String::from("Name"),
+        String::from("Part 1"),
+        String::from("Part 2"),
+        String::from("Parse"),
+        String::from("Time 1"),
+        String::from("Time 2"),
+        String::from("Total"),
+    ]];
+    // records for each row, so they come out in day order
+    let mut records: Vec<Vec<Record>> = vec![Vec::new()];
+    let mut allocation_rows = vec![[
+        String::from("Day"),
+        String::from("Step"),
+        String::from("Allocations"),
+        String::from("Bytes"),
+        String::from("Peak"),
+    ]];
+    let mut total = Duration::ZERO;
+    let mut failures = Vec::new();
+
+    let mut runnable = Vec::new();
+    for &day in days {
+        for (name, input) in load_inputs(day, args) {
+            let mut row: [String; 8] = Default::default();
+            row[0] = day_cell(day, name.as_deref());
+            let mut status = None;
+
+            match find_day(YEARS, args.year, day) {
+                None => {
+                    row[2] = String::from("not implemented");
+                    status = Some("not_implemented");
+                }
+                Some(entry) => {
+                    row[1] = entry.name.to_string();
+                    match input {
+                        Ok(input) => {
+                            runnable.push((rows.len(), day, name.clone(), (entry.solution, input)))
+                        }
+                        Err(_) => {
+                            row[2] = String::from("no input");
+                            status = Some("no_input");
+                        }
+                    }
+                }
+            }
+            rows.push(row);
+            let target = (args.year, day, name.as_deref());
+            records.push(
+                status
+                    .map(|status| run_record(target, None, status, None, None, None, None))
+                    .into_iter()
+                    .collect(),
+            );
+        }
+    }
+
+    record_panics();
+    let start = Instant::now();
+    let (positions, inputs): (Vec<_>, Vec<_>) = runnable
+        .into_iter()
+        .map(|(idx, day, name, input)| ((idx, day, name), input))
+        .unzip();
+    let runs = run_days(args.jobs, &inputs, args.part, args.timeout);
+    let wall_time = start.elapsed();
+
+    for ((idx, day, name), run) in positions.into_iter().zip(runs) {
+        let name = name.as_deref();
+        // answers are for the default input, not for everyone's own
+        let answers = answers.filter(|_| name.is_none());
+        let ledger = if name.is_none() { ledger } else { &[] };
+        let row = &mut rows[idx];
+        let run = match run {
+            Ok(run) => run,
+            Err(failure) => {
+                row[2] = failure.status().to_string();
+                records[idx].push(run_record(
+                    (args.year, day, name),
+                    None,
+                    failure.field(),
+                    None,
+                    Some(&failure),
+                    None,
+                    None,
+                ));
+                failures.push((day, step_label(name, "parse"), failure));
+                continue;
+            }
+        };
+
+        let mut day_time = run.parse_time;
+        row[4] = format!("{:.2?}", run.parse_time);
+        if let Some(allocations) = run.parse_allocations {
+            allocation_rows.push(allocation_row(&row[0], "parse", allocations));
+        }
+        for idx in [2, 3, 5, 6] {
+            row[idx] = String::from("-");
+        }
+
+        for part_run in run.parts {
+            let part = part_run.part;
+            let column = part as usize - 1;
+            day_time += part_run.elapsed;
+            let (record_status, answer, failure) = match part_run.answer {
+                Ok(answer) => {
+                    warn_known(ledger, day, part, &answer);
+                    let status = match answers {
+                        Some(answers) => check(answers, day, part, &answer)
+                            .to_string()
+                            .to_lowercase(),
+                        None => String::from("ok"),
+                    };
+                    row[2 + column] = with_verdict(answers, day, part, answer.clone());
+                    (status, Some(answer), None)
+                }
+                Err(failure) => {
+                    row[2 + column] = failure.status().to_string();
+                    let status = failure.field().to_string();
+                    (status, None, Some(failure))
+                }
+            };
+            row[5 + column] = format!("{:.2?}", part_run.elapsed);
+            let step = format!("part {}", part);
+            if let Some(allocations) = part_run.allocations {
+                allocation_rows.push(allocation_row(&row[0], &step, allocations));
+            }
+            records[idx].push(run_record(
+                (args.year, day, name),
+                Some(part),
+                &record_status,
+                answer,
+                failure.as_ref(),
+                Some((run.parse_time, part_run.elapsed)),
+                part_run.allocations,
+            ));
+            if let Some(failure) = failure {
+                failures.push((day, step_label(name, &step), failure));
+            }
+        }
+
+        row[7] = format!("{:.2?}", day_time);
+        total += day_time;
+    }
+
+    if args.format == Format::Text {
+        print_table(&rows);
+        println!("Total time: {:.2?}", total);
+        println!("Wall time: {:.2?} on {} thread(s)", wall_time, args.jobs);
+        if allocation_rows.len() > 1 {
+            println!();
+            print_table(&allocation_rows);
+        }
+    } else {
+        print_records(args.format, &records.concat());
+    }
+
+    report_failures(&failures)
+}
+
+fn run_bench(days: &[i32], args: &Args) -> ExitCode {
+    let baseline_path = baseline_path(args.year);
+    let previous = read_baseline(&baseline_path).ok();
+    let mut current = previous.clone().unwrap_or_default();
+    let mut regressions = Vec::new();
+    let mut failures = Vec::new();
+    let mut records = Vec::new();
+
+    let mut rows = vec![[
+        String::from("Day"),
+        String::from("Part"),
+        String::from("Min"),
+        String::from("Median"),
+        String::from("Mean"),
+        String::from("p95"),
+        String::from("Stddev"),
+        String::from("Baseline"),
+        String::from("Change"),
+    ]];
+
+    record_panics();
+    for &day in days {
+        let status_row = |status: &str| {
+            let mut row: [String; 9] = Default::default();
+            row[0] = format!("{:02}", day);
+            row[1] = status.to_string();
+            row
+        };
+
+        let Some(entry) = find_day(YEARS, args.year, day) else {
+            rows.push(status_row("not implemented"));
+            records.push(bench_record(
+                (args.year, day),
+                None,
+                "not_implemented",
+                None,
+                None,
+                None,
+            ));
+            continue;
+        };
+        let Ok(input) = load_input(day, args) else {
+            rows.push(status_row("no input"));
+            records.push(bench_record(
+                (args.year, day),
+                None,
+                "no_input",
+                None,
+                None,
+                None,
+            ));
+            continue;
+        };
+
+        let parse_fn = entry.solution.parse;
+        let parsed = match catch_step(|| parse_fn(&input)) {
+            Ok(parsed) => parsed,
+            Err(failure) => {
+                rows.push(status_row(failure.status()));
+                records.push(bench_record(
+                    (args.year, day),
+                    Some(0),
+                    failure.field(),
+                    None,
+                    None,
+                    None,
+                ));
+                failures.push((day, String::from("parse"), failure));
+                continue;
+            }
+        };
+
+        let mut measurements = vec![(0, measure(|| parse_fn(&input), args.warmup, args.samples))];
+        for (part, part_fn) in entry.solution.parts(args.part) {
+            // measured only once it's known not to fail
+            if let Err(failure) = catch_step(|| part_fn(&parsed)) {
+                let mut row = status_row(&part.to_string());
+                row[2] = failure.status().to_string();
+                rows.push(row);
+                records.push(bench_record(
+                    (args.year, day),
+                    Some(part),
+                    failure.field(),
+                    None,
+                    None,
+                    None,
+                ));
+                failures.push((day, format!("part {}", part), failure));
+                continue;
+            }
+
+            let stats = measure(|| part_fn(&parsed), args.warmup, args.samples);
+            measurements.push((part, stats));
+        }
+
+        for (part, stats) in measurements {
+            current.insert((day, part), stats.median);
+
+            let baseline = previous
+                .as_ref()
+                .and_then(|previous| previous.get(&(day, part)).copied());
+            let change = baseline.and_then(|baseline| change_percent(baseline, stats.median));
+            let regressed = change.is_some_and(|change| change > args.threshold);
+            let baseline_cell =
+                baseline.map_or(String::from("-"), |baseline| format!("{:.2?}", baseline));
+            let change_cell = match change {
+                Some(change) if regressed => {
+                    regressions.push((day, part, change));
+                    format!("{:+.1}% REGRESSION", change)
+                }
+                Some(change) => format!("{:+.1}%", change),
+                None => String::from("-"),
+            };
+            let status = if regressed { "regression" } else { "ok" };
+            records.push(bench_record(
+                (args.year, day),
+                Some(part),
+                status,
+                Some(&stats),
+                baseline,
+                change,
+            ));
+
+            rows.push([
+                format!("{:02}", day),
+                step_name(part, ""),
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.p95),
+                format!("{:.2?}", stats.stddev),
+                baseline_cell,
+                change_cell,
+            ]);
+        }
+    }
+
+    if args.format == Format::Text {
+        println!(
+            "Warmup: {} runs, samples: {} runs",
+            args.warmup, args.samples
+        );
+        print_table(&rows);
+    } else {
+        print_records(args.format, &records);
+    }
+
+    if previous.is_none() || args.save_baseline {
+        match write_baseline(&baseline_path, &current) {
+            // keep stdout to the records in the structured formats
+            Ok(()) if args.format == Format::Text => {
+                println!("Saved baseline to \"{}\"", baseline_path.display())
+            }
+            Ok(()) => eprintln!("Saved baseline to \"{}\"", baseline_path.display()),
+            Err(e) => {
+                eprintln!(
+                    "failed to write baseline \"{}\": {}",
+                    baseline_path.display(),
+                    e
+                );
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if !regressions.is_empty() {
+        eprintln!(
+            "{} step(s) slower than the baseline by more than {}%:",
+            regressions.len(),
+            args.threshold
+        );
+        for (day, part, change) in regressions.iter() {
+            eprintln!(
+                "  day {:02} {}: {:+.1}%",
+                day,
+                step_name(*part, "part "),
+                change
+            );
+        }
+    }
+
+    if !failures.is_empty() {
+        report_failures(&failures)
+    } else if !regressions.is_empty() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn run_verify(days: &[i32], args: &Args, answers: Option<&Answers>) -> ExitCode {
+    let Some(answers) = answers else {
+        eprintln!("error: no answers file found");
+        return ExitCode::FAILURE;
+    };
+
+    let mut rows = vec![[
+        String::from("Day"),
+        String::from("Part"),
+        String::from("Expected"),
+        String::from("Actual"),
+        String::from("Status"),
+    ]];
+    let mut mismatches = 0;
+    let mut failures = Vec::new();
+
+    // each day with known answers, along with the position of its input in
+    // the days to run or why it can't be run
+    let mut checks = Vec::new();
+    let mut inputs = Vec::new();
+    for &day in days {
+        let known: Vec<_> = answers
+            .range((day, i32::MIN)..=(day, i32::MAX))
+            .map(|(&(_, part), expected)| (part, expected))
+            .collect();
+        if known.is_empty() {
+            continue;
+        }
+
+        let position = match (
+            find_day(YEARS, args.year, day),
+            read_day_input(args.year, day, None),
+        ) {
+            (None, _) => Err("not implemented"),
+            (Some(_), Err(_)) => Err("no input"),
+            (Some(entry), Ok(input)) => {
+                inputs.push((entry.solution, prepare_input(input, args)));
+                Ok(inputs.len() - 1)
+            }
+        };
+        checks.push((day, known, position));
+    }
+
+    record_panics();
+    let runs = run_days(args.jobs, &inputs, None, args.timeout);
+
+    for (day, known, position) in checks {
+        let run = position.map(|idx| &runs[idx]);
+        if let Ok(Err(failure)) = run {
+            failures.push((day, String::from("parse"), failure.clone()));
+        }
+
+        for (part, expected) in known {
+            let (actual, status, passed) = match run {
+                Err(status) => (String::new(), status.to_string(), false),
+                Ok(Err(failure)) => (String::new(), failure.status().to_string(), false),
+                Ok(Ok(run)) => match run.parts.iter().find(|part_run| part_run.part == part) {
+                    None => (String::new(), String::from("unknown part"), false),
+                    Some(part_run) => match &part_run.answer {
+                        Ok(actual) => {
+                            let verdict = check(answers, day, part, actual);
+                            (
+                                actual.clone(),
+                                verdict.to_string(),
+                                verdict == Verdict::Pass,
+                            )
+                        }
+                        Err(failure) => {
+                            failures.push((day, format!("part {}", part), failure.clone()));
+                            (String::new(), failure.status().to_string(), false)
+                        }
+                    },
+                },
+            };
+
+            if !passed {
+                mismatches += 1;
+            }
+            rows.push([
+                format!("{:02}", day),
+                part.to_string(),
+                expected.clone(),
+                actual,
+                status,
+            ]);
+        }
+    }
+
+    print_table(&rows);
+    let total = rows.len() - 1;
+    println!("{} passed, {} failed", total - mismatches, mismatches);
+
+    if !failures.is_empty() {
+        return report_failures(&failures);
+    }
+    if mismatches == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Runs the worked examples of each day, the registered ones followed by
+/// those in its examples file, and compares them with the answers given in
+/// the puzzle text.
+fn run_example(days: &[i32], args: &Args) -> ExitCode {
+    let mut rows = vec![[
+        String::from("Day"),
+        String::from("Example"),
+        String::from("Part"),
+        String::from("Expected"),
+        String::from("Actual"),
+        String::from("Status"),
+    ]];
+    let mut mismatches = 0;
+    let mut failures = Vec::new();
+
+    record_panics();
+    for &day in days {
+        let Some(entry) = find_day(YEARS, args.year, day) else {
+            rows.push([
+                format!("{:02}", day),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::from("not implemented"),
+            ]);
+            mismatches += 1;
+            continue;
+        };
+
+        let imported = read_examples(args.year, day).unwrap_or_default();
+        let registered = entry.examples.iter().enumerate().map(|(number, example)| {
+            let answers = [example.part1, example.part2];
+            ((number + 1).to_string(), example.input, answers)
+        });
+        let imported = imported.iter().enumerate().map(|(number, example)| {
+            let answers = [example.part1.as_deref(), example.part2.as_deref()];
+            (
+                format!("imported {}", number + 1),
+                example.input.as_str(),
+                answers,
+            )
+        });
+
+        for (label, input, [part1, part2]) in registered.chain(imported) {
+            let expected: Vec<_> = [(1, part1), (2, part2)]
+                .into_iter()
+                .filter_map(|(part, expected)| Some((part, expected?)))
+                .filter(|(part, _)| args.part.is_none_or(|selected| selected == *part))
+                .collect();
+            if expected.is_empty() {
+                continue;
+            }
+            // only run the parts the example has answers for
+            let selected = match expected[..] {
+                [(part, _)] => Some(part),
+                _ => args.part,
+            };
+            let input = input.to_string();
+            let run = run_days(1, &[(entry.solution, input)], selected, args.timeout)
+                .pop()
+                .expect("one run per example");
+
+            let mut actuals: Vec<(i32, Result<String, Failure>)> = match run {
+                Ok(run) => run
+                    .parts
+                    .into_iter()
+                    .map(|part_run| (part_run.part, part_run.answer))
+                    .collect(),
+                Err(failure) => expected
+                    .iter()
+                    .map(|&(part, _)| (part, Err(failure.clone())))
+                    .collect(),
+            };
+
+            for (part, expected) in expected {
+                let idx = actuals
+                    .iter()
+                    .position(|(actual_part, _)| *actual_part == part);
+                let (actual, status) = match idx.map(|idx| actuals.remove(idx).1) {
+                    Some(Ok(actual)) if actual == expected => (actual, Verdict::Pass.to_string()),
+                    Some(Ok(actual)) => {
+                        mismatches += 1;
+                        (actual, Verdict::Fail.to_string())
+                    }
+                    Some(Err(failure)) => {
+                        let status = failure.status().to_string();
+                        let step = format!("example {} part {}", label, part);
+                        failures.push((day, step, failure));
+                        (String::new(), status)
+                    }
+                    None => {
+                        mismatches += 1;
+                        (String::new(), String::from("unknown part"))
+                    }
+                };
+
+                rows.push([
+                    format!("{:02}", day),
+                    label.clone(),
+                    part.to_string(),
+                    expected.to_string(),
+                    actual,
+                    status,
+                ]);
+            }
+        }
+    }
+
+    print_table(&rows);
+    let failed = mismatches + failures.len();
+    let checked = rows.len() - 1;
+    println!("{} passed, {} failed", checked - failed, failed);
+
+    if !failures.is_empty() {
+        return report_failures(&failures);
+    }
+    if mismatches == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn run_new(year: i32, day: i32) -> ExitCode {
+    match new_day(year, day) {
+        Ok(written) => {
+            for path in written {
+                println!("Wrote \"{}\"", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprint!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Writes the examples found in a saved puzzle page to the day's examples
+/// file, replacing the examples imported before.
+fn run_import_examples(day: i32, args: &Args) -> ExitCode {
+    let page = args.page.as_ref().expect("import-examples is given a page");
+    let html = match read_input(page) {
+        Ok(html) => html,
+        Err(e) => {
+            eprint!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let examples = extract_examples(&html);
+    if examples.is_empty() {
+        eprintln!("error: no examples found in \"{}\"", page.display());
+        return ExitCode::FAILURE;
+    }
+
+    let mut rows = vec![[
+        String::from("Example"),
+        String::from("Lines"),
+        String::from("Part 1"),
+        String::from("Part 2"),
+    ]];
+    for (number, example) in examples.iter().enumerate() {
+        rows.push([
+            (number + 1).to_string(),
+            example.input.lines().count().to_string(),
+            example.part1.clone().unwrap_or_default(),
+            example.part2.clone().unwrap_or_default(),
+        ]);
+    }
+    print_table(&rows);
+
+    let path = examples_path(args.year, day);
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(&path, render_examples(&examples)));
+    match written {
+        Ok(()) => {
+            println!("Wrote \"{}\"", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("failed to write \"{}\": {}", path.display(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Generates an input for the day from `--seed` and `--size`. It's written to
+/// the named input given by `--input-name`, or printed with the answers going
+/// to stderr so the input can be piped to a file.
+fn run_gen(day: i32, args: &Args) -> ExitCode {
+    let Some(entry) = find_day(YEARS, args.year, day) else {
+        eprintln!("error: {} day {} is not implemented", args.year, day);
+        return ExitCode::FAILURE;
+    };
+    let Some(generate) = entry.generate else {
+        eprintln!("error: {} day {} has no generator", args.year, day);
+        return ExitCode::FAILURE;
+    };
+    let generated = generate(&mut Rng::new(args.seed), args.size);
+
+    let Some(name) = &args.input_name else {
+        print!("{}", generated.input);
+        eprintln!("Part 1: {}", generated.part1);
+        eprintln!("Part 2: {}", generated.part2);
+        return ExitCode::SUCCESS;
+    };
+    let path = named_input_path(args.year, day, name);
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(&path, &generated.input));
+    match written {
+        Ok(()) => {
+            println!("Wrote \"{}\"", path.display());
+            println!("Part 1: {}", generated.part1);
+            println!("Part 2: {}", generated.part2);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("failed to write \"{}\": {}", path.display(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Clears the terminal and re-runs the day whenever its input or one of the
+/// `--watch` files changes, until interrupted.
+fn run_watch(day: i32, args: &Args, answers: Option<&Answers>, ledger: &[Entry]) -> ExitCode {
+    let mut paths = vec![input_file(day, args)];
+    paths.extend(args.watch.iter().cloned());
+
+    watch(&paths, POLL_INTERVAL, || {
+        print!("\x1b[2J\x1b[H");
+        let watched: Vec<_> = paths
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+        println!("Watching {} (Ctrl-C to stop)", watched.join(", "));
+        run_single(day, args, answers, ledger);
+        true
+    });
+    ExitCode::SUCCESS
+}
+
+/// Records the feedback on a submitted answer with `--guess`, lists the
+/// answers submitted for the day otherwise.
+fn run_answer(day: i32, args: &Args) -> ExitCode {
+    let ledger = read_ledger(args.year);
+    let (Some(guess), Some(feedback), Some(part)) = (&args.guess, args.feedback, args.part) else {
+        let mut rows = vec![[
+            String::from("Part"),
+            String::from("Answer"),
+            String::from("Feedback"),
+        ]];
+        for entry in ledger
+            .iter()
+            .filter(|entry| entry.day == day && args.part.is_none_or(|part| part == entry.part))
+        {
+            rows.push([
+                entry.part.to_string(),
+                entry.answer.clone(),
+                entry.feedback.to_string(),
+            ]);
+        }
+        if rows.len() == 1 {
+            println!("No answers recorded for day {:02}", day);
+        } else {
+            print_table(&rows);
+        }
+        return ExitCode::SUCCESS;
+    };
+
+    match check_answer(&ledger, day, part, guess) {
+        Some(Warning::Submitted(previous)) if previous == feedback => {
+            println!(
+                "Day {:02} part {}: {} is already recorded as {}",
+                day, part, guess, feedback
+            );
+            return ExitCode::SUCCESS;
+        }
+        Some(warning) => eprintln!(
+            "warning: day {:02} part {}: {} {}",
+            day, part, guess, warning
+        ),
+        None => {}
+    }
+
+    let entry = Entry {
+        day,
+        part,
+        answer: guess.clone(),
+        feedback,
+    };
+    let path = ledger_path(args.year);
+    match append_entry(&path, &entry) {
+        Ok(()) => {
+            println!(
+                "Recorded day {:02} part {}: {} as {}",
+                day, part, guess, feedback
+            );
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("failed to write \"{}\": {}", path.display(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Timeouts are written in whole seconds when possible, see
+/// [`cli::parse_duration`].
+fn format_timeout(timeout: Duration) -> String {
+    if timeout.subsec_nanos() == 0 {
+        format!("{}s", timeout.as_secs())
+    } else {
+        format!("{}ms", timeout.as_secs_f64() * 1000.0)
+    }
+}
+
+/// Prints the settings in effect after applying the config file, the
+/// environment and the flags, in the config file's format.
+fn run_config_show(args: &Args, config: &Config) -> ExitCode {
+    match &config.path {
+        Some(path) => println!("# read from \"{}\"", path.display()),
+        None => println!("# no aoc.toml found, using the defaults"),
+    }
+    let optional = |key: &str, value: Option<String>| match value {
+        Some(value) => format!("{} = {}", key, value),
+        None => format!("# {} is not set", key),
+    };
+
+    println!("year = {}", args.year);
+    println!(
+        "input_dir = {}",
+        toml_string(&input_dir().display().to_string())
+    );
+    println!("format = {}", toml_string(&args.format.to_string()));
+    println!("jobs = {}", args.jobs);
+    println!(
+        "{}",
+        optional(
+            "timeout",
+            args.timeout
+                .map(|timeout| toml_string(&format_timeout(timeout)))
+        )
+    );
+    println!(
+        "{}",
+        optional("input_name", args.input_name.as_deref().map(toml_string))
+    );
+    println!();
+    println!("[bench]");
+    println!("samples = {}", args.samples);
+    println!("warmup = {}", args.warmup);
+    println!("threshold = {}", args.threshold);
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let config = match load_config() {
+        Ok(config) => config,
+        Err(e) => {
+            eprint!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = match parse_args(&args, &config) {
+        Ok(args) => args,
+        Err(e) => {
+            eprint!("error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // `--input-dir` takes precedence over `AOC_INPUT_DIR`, which takes
+    // precedence over the config file. Nothing else runs yet, so setting the
+    // variable can't race with reading it.
+    let dir = match (&args.input_dir, &config.input_dir) {
+        (Some(dir), _) => Some(dir),
+        (None, Some(dir)) if env::var_os("AOC_INPUT_DIR").is_none() => Some(dir),
+        _ => None,
+    };
+    if let Some(dir) = dir {
+        env::set_var("AOC_INPUT_DIR", dir);
+    }
+
+    if uses_legacy_inputs(args.year) {
+        eprintln!(
+            "warning: reading inputs from \"{}\", move them to \"{}\"",
+            input_dir().display(),
+            input_dir().join(args.year.to_string()).display()
+        );
+    }
+
+    let days = match (&args.days, find_year(YEARS, args.year)) {
+        (DaySelection::Days(days), _) => days.clone(),
+        (DaySelection::All, Some(year)) => year.days.iter().map(|entry| entry.day).collect(),
+        (DaySelection::All, None) if args.command == Command::Config => Vec::new(),
+        (DaySelection::All, None) => {
+            eprint!("error: {}", DayError::UnknownYear(args.year));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // answers are for the puzzle inputs, they don't apply to a custom input
+    let answers = match (&args.input, &args.input_name) {
+        (None, None) => read_answers(args.year),
+        _ => None,
+    };
+    let ledger = match (&args.input, &args.input_name) {
+        (None, None) => read_ledger(args.year),
+        _ => Vec::new(),
+    };
+
+    match args.command {
+        Command::Bench => run_bench(&days, &args),
+        Command::Verify => run_verify(&days, &args, answers.as_ref()),
+        Command::New => run_new(args.year, days[0]),
+        Command::Watch => run_watch(days[0], &args, answers.as_ref(), &ledger),
+        Command::Example => run_example(&days, &args),
+        Command::Answer => run_answer(days[0], &args),
+        Command::Config => run_config_show(&args, &config),
+        Command::ImportExamples => run_import_examples(days[0], &args),
+        Command::Gen => run_gen(days[0], &args),
+        Command::Run => match (&args.days, &days[..], args.format) {
+            (DaySelection::Days(_), &[day], Format::Text) if !args.all_inputs => {
+                run_single(day, &args, answers.as_ref(), &ledger)
+            }
+            _ => run_many(&days, &args, answers.as_ref(), &ledger),
+        },
+    }
 }
@@ -0,0 +1,273 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use crate::bench::Stats;
+use crate::failure::Failure;
+use crate::runner::{find_day, Allocations};
+use crate::YEARS;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Format::Text => write!(f, "text"),
+            Format::Json => write!(f, "json"),
+            Format::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Field {
+    Null,
+    Int(i64),
+    Float(f64),
+    Text(String),
+}
+
+impl From<i32> for Field {
+    fn from(value: i32) -> Self {
+        Field::Int(value.into())
+    }
+}
+
+impl From<u64> for Field {
+    fn from(value: u64) -> Self {
+        Field::Int(value.try_into().unwrap_or(i64::MAX))
+    }
+}
+
+impl From<f64> for Field {
+    fn from(value: f64) -> Self {
+        Field::Float(value)
+    }
+}
+
+impl From<&str> for Field {
+    fn from(value: &str) -> Self {
+        Field::Text(value.to_string())
+    }
+}
+
+impl From<String> for Field {
+    fn from(value: String) -> Self {
+        Field::Text(value)
+    }
+}
+
+/// Durations are written in whole nanoseconds.
+impl From<Duration> for Field {
+    fn from(value: Duration) -> Self {
+        Field::Int(value.as_nanos().try_into().unwrap_or(i64::MAX))
+    }
+}
+
+impl<T: Into<Field>> From<Option<T>> for Field {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Field::Null, Into::into)
+    }
+}
+
+/// A row of named fields, every record of an output has the same names in
+/// the same order.
+pub type Record = Vec<(&'static str, Field)>;
+
+fn json_string(text: &str) -> String {
+    let mut result = String::from("\"");
+    for ch in text.chars() {
+        match ch {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            ch if ch.is_control() => result.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => result.push(ch),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn json_field(field: &Field) -> String {
+    match field {
+        Field::Int(value) => value.to_string(),
+        Field::Float(value) if value.is_finite() => value.to_string(),
+        Field::Null | Field::Float(_) => String::from("null"),
+        Field::Text(text) => json_string(text),
+    }
+}
+
+/// Writes the records as a JSON array with one object per line.
+pub fn to_json(records: &[Record]) -> String {
+    let objects: Vec<String> = records
+        .iter()
+        .map(|record| {
+            let fields: Vec<String> = record
+                .iter()
+                .map(|(name, field)| format!("{}: {}", json_string(name), json_field(field)))
+                .collect();
+            format!("  {{{}}}", fields.join(", "))
+        })
+        .collect();
+
+    if objects.is_empty() {
+        String::from("[]\n")
+    } else {
+        format!("[\n{}\n]\n", objects.join(",\n"))
+    }
+}
+
+fn csv_text(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn csv_field(field: &Field) -> String {
+    match field {
+        Field::Null => String::new(),
+        Field::Int(value) => value.to_string(),
+        Field::Float(value) => value.to_string(),
+        Field::Text(text) => csv_text(text),
+    }
+}
+
+/// Writes the records as CSV with a header row taken from the first record's
+/// field names. Null fields are left empty.
+pub fn to_csv(records: &[Record]) -> String {
+    let Some(first) = records.first() else {
+        return String::new();
+    };
+
+    let mut lines = vec![first
+        .iter()
+        .map(|(name, _)| csv_text(name))
+        .collect::<Vec<_>>()
+        .join(",")];
+    for record in records {
+        let fields: Vec<String> = record.iter().map(|(_, field)| csv_field(field)).collect();
+        lines.push(fields.join(","));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Prints the records in a machine-readable format, doing nothing for
+/// [`Format::Text`] which every command prints its own way.
+pub fn print_records(format: Format, records: &[Record]) {
+    match format {
+        Format::Text => {}
+        Format::Json => print!("{}", to_json(records)),
+        Format::Csv => print!("{}", to_csv(records)),
+    }
+}
+
+/// A structured output record for one part of a day, or for the whole day
+/// when it couldn't be parsed or run at all. `input` names the input when
+/// running one of the day's named inputs.
+pub fn run_record(
+    (year, day, input): (i32, i32, Option<&str>),
+    part: Option<i32>,
+    status: &str,
+    answer: Option<String>,
+    error: Option<&Failure>,
+    times: Option<(Duration, Duration)>,
+    allocations: Option<Allocations>,
+) -> Record {
+    vec![
+        ("year", year.into()),
+        ("day", day.into()),
+        (
+            "name",
+            find_day(YEARS, year, day).map(|entry| entry.name).into(),
+        ),
+        ("input", input.into()),
+        ("part", part.into()),
+        ("answer", answer.into()),
+        ("status", status.into()),
+        (
+            "error",
+            error.map(|e| e.to_string().trim_end().to_string()).into(),
+        ),
+        ("parse_ns", times.map(|(parse_time, _)| parse_time).into()),
+        ("time_ns", times.map(|(_, time)| time).into()),
+        ("allocations", allocations.map(|a| a.count).into()),
+        ("alloc_bytes", allocations.map(|a| a.bytes).into()),
+        ("peak_bytes", allocations.map(|a| a.peak).into()),
+    ]
+}
+
+/// A structured output record for a benchmarked step, part 0 being the
+/// parse as in the baseline file.
+pub fn bench_record(
+    (year, day): (i32, i32),
+    part: Option<i32>,
+    status: &str,
+    stats: Option<&Stats>,
+    baseline: Option<Duration>,
+    change: Option<f64>,
+) -> Record {
+    vec![
+        ("year", year.into()),
+        ("day", day.into()),
+        ("part", part.into()),
+        ("status", status.into()),
+        ("min_ns", stats.map(|stats| stats.min).into()),
+        ("median_ns", stats.map(|stats| stats.median).into()),
+        ("mean_ns", stats.map(|stats| stats.mean).into()),
+        ("p95_ns", stats.map(|stats| stats.p95).into()),
+        ("stddev_ns", stats.map(|stats| stats.stddev).into()),
+        ("baseline_ns", baseline.into()),
+        ("change_percent", change.into()),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        vec![
+            vec![
+                ("day", 2.into()),
+                ("answer", "8".into()),
+                ("time_ns", Duration::from_micros(3).into()),
+            ],
+            vec![
+                ("day", 9.into()),
+                ("answer", Field::Null),
+                ("time_ns", Field::from(None::<Duration>)),
+            ],
+        ]
+    }
+
+    #[test]
+    fn writes_json() {
+        let expected = r#"[
+  {"day": 2, "answer": "8", "time_ns": 3000},
+  {"day": 9, "answer": null, "time_ns": null}
+]
+"#;
+
+        assert_eq!(to_json(&records()), expected);
+        assert_eq!(to_json(&[]), "[]\n");
+        assert_eq!(json_string("say \"hi\"\n\u{1}"), r#""say \"hi\"\n\u0001""#);
+    }
+
+    #[test]
+    fn writes_csv() {
+        let expected = "day,answer,time_ns\n2,8,3000\n9,,\n";
+
+        assert_eq!(to_csv(&records()), expected);
+        assert_eq!(csv_text("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+}
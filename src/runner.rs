@@ -1,6 +1,16 @@
+use std::any::Any;
+use std::env;
 use std::fmt::Display;
+use std::io;
 use std::num::ParseIntError;
-use std::{fs::File, io::Read};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{
+    fs::{self, File},
+    io::Read,
+};
+
+use crate::generator::Generator;
 
 pub trait Solve<T1, T2>
 where
@@ -18,10 +28,268 @@ where
     }
 }
 
+/// An input that doesn't match the puzzle format, pointing at the offending
+/// line (1-based) and what should have been there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleError {
+    pub day: i32,
+    pub line: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl PuzzleError {
+    pub fn new(day: i32, line: usize, text: &str, expected: &str) -> PuzzleError {
+        PuzzleError {
+            day,
+            line,
+            text: text.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "day {:02}, line {}: expected {}, found `{}`",
+            self.day, self.line, self.expected, self.text
+        )
+    }
+}
+
+/// Fallible variant of [`Solve`] for days that validate their input instead
+/// of panicking on it. Every [`Solve`] implementation is also a `TrySolve`.
+pub trait TrySolve<T1, T2>
+where
+    T1: ToString,
+    T2: ToString,
+{
+    fn try_part1(input: &str) -> Result<T1, PuzzleError>;
+    fn try_part2(input: &str) -> Result<T2, PuzzleError>;
+}
+
+impl<S, T1, T2> TrySolve<T1, T2> for S
+where
+    S: Solve<T1, T2>,
+    T1: ToString,
+    T2: ToString,
+{
+    fn try_part1(input: &str) -> Result<T1, PuzzleError> {
+        Ok(S::part1(input))
+    }
+    fn try_part2(input: &str) -> Result<T2, PuzzleError> {
+        Ok(S::part2(input))
+    }
+}
+
+/// Variant of [`TrySolve`] that parses the input once into an `Input` shared
+/// by both parts, so parsing isn't repeated and can be timed on its own.
+/// Every [`TrySolve`] implementation is also a `ParseSolve` whose `Input` is
+/// the raw text.
+pub trait ParseSolve<T1, T2>
+where
+    T1: ToString,
+    T2: ToString,
+{
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, PuzzleError>;
+    fn solve_part1(input: &Self::Input) -> Result<T1, PuzzleError>;
+    fn solve_part2(input: &Self::Input) -> Result<T2, PuzzleError>;
+}
+
+impl<S, T1, T2> ParseSolve<T1, T2> for S
+where
+    S: TrySolve<T1, T2>,
+    T1: ToString,
+    T2: ToString,
+{
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, PuzzleError> {
+        Ok(input.to_string())
+    }
+    fn solve_part1(input: &String) -> Result<T1, PuzzleError> {
+        S::try_part1(input)
+    }
+    fn solve_part2(input: &String) -> Result<T2, PuzzleError> {
+        S::try_part2(input)
+    }
+}
+
+/// A day's parsed input with its type erased, see [`Solution`].
+pub struct Parsed(Box<dyn Any + Send + Sync>);
+
+pub type ParseFn = fn(&str) -> Result<Parsed, PuzzleError>;
+pub type PartFn = fn(&Parsed) -> Result<String, PuzzleError>;
+
+/// A day's parser and both parts, with the parsed input type erased and the
+/// answers converted to strings so that all days can be run the same way.
+/// The parts must only be given input produced by `parse`.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub parse: ParseFn,
+    pub part1: PartFn,
+    pub part2: PartFn,
+}
+
+fn downcast<S, T1, T2>(input: &Parsed) -> &S::Input
+where
+    S: ParseSolve<T1, T2>,
+    S::Input: 'static,
+    T1: ToString,
+    T2: ToString,
+{
+    input
+        .0
+        .downcast_ref()
+        .expect("part was given input from another day's parser")
+}
+
+impl Solution {
+    pub const fn of<S, T1, T2>() -> Solution
+    where
+        S: ParseSolve<T1, T2>,
+        S::Input: Send + Sync + 'static,
+        T1: ToString,
+        T2: ToString,
+    {
+        Solution {
+            parse: |input| S::parse(input).map(|parsed| Parsed(Box::new(parsed))),
+            part1: |input| {
+                S::solve_part1(downcast::<S, T1, T2>(input)).map(|answer| answer.to_string())
+            },
+            part2: |input| {
+                S::solve_part2(downcast::<S, T1, T2>(input)).map(|answer| answer.to_string())
+            },
+        }
+    }
+
+    /// Returns the selected part, or both parts when none is selected.
+    pub fn parts(&self, selected: Option<i32>) -> Vec<(i32, PartFn)> {
+        [(1, self.part1), (2, self.part2)]
+            .into_iter()
+            .filter(|(part, _)| selected.is_none_or(|selected| selected == *part))
+            .collect()
+    }
+}
+
+/// Heap allocations made by a step, for runners that count them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Allocations {
+    pub count: u64,
+    pub bytes: u64,
+    /// Most bytes the step had allocated at once.
+    pub peak: u64,
+}
+
+/// A part's answer and how long it took. The error is a [`PuzzleError`]
+/// unless the caller tracks other ways a part can fail.
+pub struct PartRun<E = PuzzleError> {
+    pub part: i32,
+    pub answer: Result<String, E>,
+    pub elapsed: Duration,
+    pub allocations: Option<Allocations>,
+}
+
+pub struct DayRun<E = PuzzleError> {
+    pub parse_time: Duration,
+    pub parse_allocations: Option<Allocations>,
+    pub parts: Vec<PartRun<E>>,
+}
+
+/// A worked example from the puzzle text with the answers it should produce.
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+/// Everything the runner needs to know about an implemented day.
+pub struct Day {
+    pub day: i32,
+    pub name: &'static str,
+    pub solution: Solution,
+    pub examples: &'static [Example],
+    /// Generates inputs with known answers, for days that have a generator.
+    pub generate: Option<Generator>,
+}
+
+/// Declares the day modules and collects them into the `DAYS` registry, so
+/// a day only has to be listed once to be runnable.
+///
+/// Each entry is `day => module::Type, "Puzzle name";` where the module
+/// exports an `EXAMPLES` slice and `Type` implements [`ParseSolve`], directly
+/// or through [`TrySolve`] or [`Solve`]. A day with an input generator lists
+/// the module's generator function last, like `"Name", generate;`.
+macro_rules! register_days {
+    (@generate $module:ident) => {
+        None
+    };
+    (@generate $module:ident $generate:ident) => {
+        Some($module::$generate as $crate::generator::Generator)
+    };
+    ($($day:literal => $module:ident::$solver:ident, $name:literal $(, $generate:ident)?;)*) => {
+        $(pub mod $module;)*
+
+        pub const DAYS: &[$crate::runner::Day] = &[$(
+            $crate::runner::Day {
+                day: $day,
+                name: $name,
+                solution: $crate::runner::Solution::of::<$module::$solver, _, _>(),
+                examples: $module::EXAMPLES,
+                generate: $crate::runner::register_days!(@generate $module $($generate)?),
+            },
+        )*];
+    };
+}
+pub(crate) use register_days;
+
+/// The days implemented for one event.
+pub struct Year {
+    pub year: i32,
+    pub days: &'static [Day],
+}
+
+/// Declares the year modules and collects them into the `YEARS` registry.
+///
+/// Each entry is `year => module;` where the module lists its days with
+/// [`register_days!`].
+macro_rules! register_years {
+    ($($year:literal => $module:ident;)*) => {
+        $(pub mod $module;)*
+
+        pub const YEARS: &[$crate::runner::Year] = &[$(
+            $crate::runner::Year {
+                year: $year,
+                days: $module::DAYS,
+            },
+        )*];
+    };
+}
+pub(crate) use register_years;
+
+pub fn find_year(years: &'static [Year], year: i32) -> Option<&'static Year> {
+    years.iter().find(|entry| entry.year == year)
+}
+
+pub fn find_day(years: &'static [Year], year: i32, day: i32) -> Option<&'static Day> {
+    find_year(years, year)?
+        .days
+        .iter()
+        .find(|entry| entry.day == day)
+}
+
 #[derive(Debug, Clone)]
 pub enum DayError {
     NotADay,
     NotANumber(ParseIntError),
+    EmptyRange(i32, i32),
+    UnknownYear(i32),
+    Input(PathBuf, io::ErrorKind),
 }
 
 impl Display for DayError {
@@ -32,6 +300,21 @@ impl Display for DayError {
                 "number is not an advent of code day, should be between 1-25"
             ),
             DayError::NotANumber(e) => writeln!(f, "not a number: {}", e),
+            DayError::EmptyRange(start, end) => {
+                writeln!(f, "range {}-{} does not contain any day", start, end)
+            }
+            DayError::UnknownYear(year) if *year < FIRST_YEAR => writeln!(
+                f,
+                "{} is not an advent of code year, the first event was in {}",
+                year, FIRST_YEAR
+            ),
+            DayError::UnknownYear(year) => writeln!(f, "no days of {} are implemented", year),
+            DayError::Input(path, io::ErrorKind::NotFound) => {
+                writeln!(f, "input \"{}\" does not exist", path.display())
+            }
+            DayError::Input(path, kind) => {
+                writeln!(f, "failed to read input \"{}\": {}", path.display(), kind)
+            }
         }
     }
 }
@@ -50,11 +333,268 @@ pub fn parse_day(day_str: &str) -> Result<i32, DayError> {
     }
 }
 
-pub fn read_day_input(day: i32) -> String {
-    let filename = format!("./input/{:02}.input", day);
-    let mut file = File::open(&filename)
-        .unwrap_or_else(|_| panic!("failed to open \"{}\", does it exist?", filename));
+/// The year of the first event.
+pub const FIRST_YEAR: i32 = 2015;
+
+pub fn parse_year(year_str: &str) -> Result<i32, DayError> {
+    match year_str.parse() {
+        Ok(year) if year >= FIRST_YEAR => Ok(year),
+        Ok(year) => Err(DayError::UnknownYear(year)),
+        Err(e) => Err(DayError::NotANumber(e)),
+    }
+}
+
+/// Parses a selection of days, either a single day, a range like `1-5` or a
+/// comma separated list of both like `1,3,7-9`. Days are returned sorted and
+/// without duplicates.
+pub fn parse_days(selection: &str) -> Result<Vec<i32>, DayError> {
+    let mut days = Vec::new();
+
+    for item in selection.split(',') {
+        match item.split_once('-') {
+            Some((start, end)) => {
+                let start = parse_day(start)?;
+                let end = parse_day(end)?;
+                if start > end {
+                    return Err(DayError::EmptyRange(start, end));
+                }
+                days.extend(start..=end);
+            }
+            None => days.push(parse_day(item)?),
+        }
+    }
+
+    days.sort();
+    days.dedup();
+    Ok(days)
+}
+
+/// Directory holding the puzzle inputs, `./input` unless overridden by the
+/// `AOC_INPUT_DIR` environment variable.
+pub fn input_dir() -> PathBuf {
+    env::var_os("AOC_INPUT_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("./input"))
+}
+
+/// Whether a directory has inputs, named inputs or answers straight in it,
+/// the way they were kept before each event got a directory of its own.
+fn has_legacy_inputs(dir: &Path) -> bool {
+    dir.join("answers.txt").is_file()
+        || !input_names_in(dir).is_empty()
+        || (1..=25).any(|day| !input_names_in(&dir.join(format!("{:02}", day))).is_empty())
+}
+
+fn year_dir_in(dir: &Path, year: i32) -> PathBuf {
+    let year_dir = dir.join(year.to_string());
+    if year == crate::DEFAULT_YEAR && !year_dir.is_dir() && has_legacy_inputs(dir) {
+        dir.to_path_buf()
+    } else {
+        year_dir
+    }
+}
+
+/// Directory holding an event's inputs, `input/<year>`. A checkout that still
+/// keeps its inputs straight in `input/` goes on using them for the default
+/// year until `input/<year>` is created.
+pub fn year_dir(year: i32) -> PathBuf {
+    year_dir_in(&input_dir(), year)
+}
+
+/// Whether the year's inputs are read from the layout without a directory
+/// per year, see [`year_dir`].
+pub fn uses_legacy_inputs(year: i32) -> bool {
+    year_dir(year) == input_dir()
+}
+
+pub fn input_path(year: i32, day: i32) -> PathBuf {
+    year_dir(year).join(format!("{:02}.input", day))
+}
+
+/// Path of one of several inputs for a day, `input/<year>/NN/<name>.input`,
+/// so that everyone sharing the repo can keep their own.
+pub fn named_input_path(year: i32, day: i32, name: &str) -> PathBuf {
+    year_dir(year)
+        .join(format!("{:02}", day))
+        .join(format!("{}.input", name))
+}
+
+/// Names of the `.input` files in a directory, sorted. A missing directory
+/// has no inputs.
+fn input_names_in(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "input" || !path.is_file() {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// Names of the day's inputs in `input/<year>/NN/`, see [`named_input_path`].
+pub fn input_names(year: i32, day: i32) -> Vec<String> {
+    input_names_in(&year_dir(year).join(format!("{:02}", day)))
+}
+
+/// Reads an input file, or stdin when the path is `-`.
+pub fn read_input(path: &Path) -> Result<String, DayError> {
     let mut contents = String::new();
-    file.read_to_string(&mut contents).unwrap();
-    contents
+    let result = if path == Path::new("-") {
+        io::stdin().read_to_string(&mut contents)
+    } else {
+        File::open(path).and_then(|mut file| file.read_to_string(&mut contents))
+    };
+
+    match result {
+        Ok(_) => Ok(contents),
+        Err(e) => Err(DayError::Input(path.to_path_buf(), e.kind())),
+    }
+}
+
+/// Normalizes an input saved by another editor or OS to what the parsers
+/// expect: no UTF-8 byte order mark, `\n` line endings and no trailing
+/// whitespace on any line. A final line ending is kept if there was one.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    // a lone `\r` is an old Mac line ending
+    let input = input.replace("\r\n", "\n").replace('\r', "\n");
+
+    let lines: Vec<&str> = input.split('\n').map(str::trim_end).collect();
+    lines.join("\n")
+}
+
+/// Reads the day's input file, or its named input when a name is given.
+pub fn read_day_input(year: i32, day: i32, name: Option<&str>) -> Result<String, DayError> {
+    match name {
+        Some(name) => read_input(&named_input_path(year, day, name)),
+        None => read_input(&input_path(year, day)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lines;
+
+    impl Solve<usize, usize> for Lines {
+        fn part1(input: &str) -> usize {
+            input.lines().count()
+        }
+        fn part2(input: &str) -> usize {
+            input.len()
+        }
+    }
+
+    #[test]
+    fn solves_both_parts() {
+        assert_eq!(
+            Lines::solve("a\nb\n"),
+            (String::from("2"), String::from("4"))
+        );
+        assert_eq!(Lines::try_part1("a\n"), Ok(1));
+    }
+
+    #[test]
+    fn parses_day_selections() {
+        assert_eq!(parse_days("7").unwrap(), vec![7]);
+        assert_eq!(parse_days("1-5").unwrap(), vec![1, 2, 3, 4, 5]);
+        assert_eq!(parse_days("7,1,3").unwrap(), vec![1, 3, 7]);
+        assert_eq!(parse_days("1-3,2,9").unwrap(), vec![1, 2, 3, 9]);
+    }
+
+    #[test]
+    fn rejects_invalid_selections() {
+        assert!(matches!(parse_days("5-1"), Err(DayError::EmptyRange(5, 1))));
+        assert!(matches!(parse_days("0-3"), Err(DayError::NotADay)));
+        assert!(matches!(parse_days("1,x"), Err(DayError::NotANumber(_))));
+    }
+
+    #[test]
+    fn parses_years() {
+        assert_eq!(parse_year("2023").unwrap(), 2023);
+        assert!(matches!(
+            parse_year("2014"),
+            Err(DayError::UnknownYear(2014))
+        ));
+        assert!(matches!(parse_year("23x"), Err(DayError::NotANumber(_))));
+        assert_eq!(
+            named_input_path(2023, 5, "alice"),
+            input_dir().join("2023").join("05").join("alice.input")
+        );
+    }
+
+    #[test]
+    fn normalizes_input() {
+        assert_eq!(normalize("1 2\r\n3 4\r\n"), "1 2\n3 4\n");
+        assert_eq!(normalize("\u{feff}seeds: 1  \n\t\nx"), "seeds: 1\n\nx");
+        assert_eq!(normalize("a\rb\r"), "a\nb\n");
+        assert_eq!(normalize("a\r\n\r\nb\n"), "a\n\nb\n");
+        assert_eq!(normalize(""), "");
+    }
+
+    #[test]
+    fn falls_back_to_legacy_inputs() {
+        let dir = env::temp_dir().join(format!("aoc2023-legacy-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("01.input"), "1abc2\n").unwrap();
+
+        let legacy = (
+            year_dir_in(&dir, crate::DEFAULT_YEAR),
+            year_dir_in(&dir, 2022),
+        );
+        let year = crate::DEFAULT_YEAR.to_string();
+        fs::create_dir_all(dir.join(&year)).unwrap();
+        let migrated = year_dir_in(&dir, crate::DEFAULT_YEAR);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(legacy, (dir.clone(), dir.join("2022")));
+        assert_eq!(migrated, dir.join(year));
+    }
+
+    #[test]
+    fn falls_back_to_legacy_named_inputs() {
+        let dir = env::temp_dir().join(format!("aoc2023-legacy-named-{}", std::process::id()));
+        fs::create_dir_all(dir.join("07")).unwrap();
+        let empty = year_dir_in(&dir, crate::DEFAULT_YEAR);
+        fs::write(dir.join("07").join("alice.input"), "32T3K 765\n").unwrap();
+        let legacy = year_dir_in(&dir, crate::DEFAULT_YEAR);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(empty, dir.join(crate::DEFAULT_YEAR.to_string()));
+        assert_eq!(legacy, dir);
+    }
+
+    #[test]
+    fn lists_named_inputs() {
+        let dir = env::temp_dir().join(format!("aoc2023-inputs-{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested.input")).unwrap();
+        for file in ["bob.input", "alice.input", "notes.txt"] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+
+        let result = input_names_in(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(result, vec!["alice", "bob"]);
+        assert!(input_names_in(Path::new("./does/not/exist")).is_empty());
+    }
+
+    #[test]
+    fn reports_missing_input() {
+        let result = read_input(Path::new("./does/not/exist.input"));
+
+        assert!(matches!(
+            result,
+            Err(DayError::Input(_, io::ErrorKind::NotFound))
+        ));
+    }
 }
@@ -0,0 +1,310 @@
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc2023::examples::{read_examples, ImportedExample};
+use aoc2023::runner::input_path;
+
+const TEMPLATE: &str = include_str!("template.rs");
+const REGISTRY_START: &str = "register_days! {";
+const YEARS_START: &str = "register_years! {";
+
+#[derive(Debug)]
+pub enum NewDayError {
+    AlreadyExists(PathBuf),
+    AlreadyRegistered(String),
+    MissingRegistry(&'static str, PathBuf),
+    Io(PathBuf, io::ErrorKind),
+}
+
+impl Display for NewDayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NewDayError::AlreadyExists(path) => {
+                writeln!(f, "\"{}\" already exists", path.display())
+            }
+            NewDayError::AlreadyRegistered(what) => writeln!(f, "{} is already registered", what),
+            NewDayError::MissingRegistry(block, path) => {
+                writeln!(f, "no `{}` block found in \"{}\"", block, path.display())
+            }
+            NewDayError::Io(path, kind) => {
+                writeln!(f, "failed to write \"{}\": {}", path.display(), kind)
+            }
+        }
+    }
+}
+
+fn src_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src")
+}
+
+/// A raw string literal holding `text`, with enough `#`s to contain it.
+fn raw_string(text: &str) -> String {
+    let mut hashes = String::from("#");
+    while text.contains(&format!("\"{}", hashes)) {
+        hashes.push('#');
+    }
+    format!("r{}\"{}\"{}", hashes, text, hashes)
+}
+
+/// The template with `Day00` renamed for the given day. With an imported
+/// example its input and answers are filled in, the answers of the tests
+/// only when they fit the template's `i32`.
+pub fn render_template(day: i32, example: Option<&ImportedExample>) -> String {
+    let template = TEMPLATE.replace("Day00", &format!("Day{:02}", day));
+    let Some(example) = example else {
+        return template;
+    };
+
+    let (head, part2_test) = template
+        .split_once("fn example_p2")
+        .expect("template has a part 2 test");
+    let mut head = head.replace("r#\"\"#", &raw_string(&example.input));
+    let mut part2_test = format!("fn example_p2{}", part2_test);
+    for (part, answer) in [(1, &example.part1), (2, &example.part2)] {
+        let Some(answer) = answer else {
+            continue;
+        };
+        head = head.replace(
+            &format!("part{}: None", part),
+            &format!("part{}: Some({:?})", part, answer),
+        );
+        let test = if part == 1 {
+            &mut head
+        } else {
+            &mut part2_test
+        };
+        if let Ok(answer) = answer.parse::<i32>() {
+            *test = test.replacen(
+                "let expected = 0;",
+                &format!("let expected = {};", answer),
+                1,
+            );
+        }
+    }
+    head + &part2_test
+}
+
+/// The module of an event that has no days yet.
+pub fn render_year(year: i32) -> String {
+    format!(
+        "//! Advent of Code {} solutions.\n\nuse crate::runner::register_days;\n\n{}\n}}\n",
+        year, REGISTRY_START
+    )
+}
+
+/// Adds an entry to a registry block whose entries start with their number,
+/// keeping it sorted. Returns `None` when there is no block or the number is
+/// already in it.
+fn insert_sorted(source: &str, block_start: &str, key: i32, entry: &str) -> Option<String> {
+    let lines: Vec<&str> = source.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.trim_start().starts_with(block_start))?;
+    let end = start + lines[start..].iter().position(|line| line.trim() == "}")?;
+
+    let mut insert_at = end;
+    for (idx, line) in lines.iter().enumerate().take(end).skip(start + 1) {
+        let registered = line
+            .split_once("=>")
+            .and_then(|(number, _)| number.trim().parse::<i32>().ok());
+        match registered {
+            Some(registered) if registered == key => return None,
+            Some(registered) if registered > key => {
+                insert_at = idx;
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    let mut result: Vec<&str> = lines[..insert_at].to_vec();
+    result.push(entry);
+    result.extend_from_slice(&lines[insert_at..]);
+
+    let mut result = result.join("\n");
+    if source.ends_with('\n') {
+        result.push('\n');
+    }
+    Some(result)
+}
+
+/// Adds the day to the `register_days!` block, keeping it sorted by day.
+/// Returns `None` when there is no block or the day is already in it.
+pub fn insert_registration(source: &str, day: i32) -> Option<String> {
+    let entry = format!(
+        "    {} => day{:02}::Day{:02}, \"Day {}\";",
+        day, day, day, day
+    );
+    insert_sorted(source, REGISTRY_START, day, &entry)
+}
+
+/// Adds the year to the `register_years!` block in `lib.rs`, see
+/// [`insert_registration`].
+pub fn insert_year(source: &str, year: i32) -> Option<String> {
+    let entry = format!("    {} => y{};", year, year);
+    insert_sorted(source, YEARS_START, year, &entry)
+}
+
+fn write_new(path: &Path, contents: &str) -> Result<(), NewDayError> {
+    let io_error = |e: io::Error| match e.kind() {
+        io::ErrorKind::AlreadyExists => NewDayError::AlreadyExists(path.to_path_buf()),
+        kind => NewDayError::Io(path.to_path_buf(), kind),
+    };
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map_err(io_error)?;
+    io::Write::write_all(&mut file, contents.as_bytes()).map_err(io_error)
+}
+
+fn read_source(path: &Path) -> Result<String, NewDayError> {
+    fs::read_to_string(path).map_err(|e| NewDayError::Io(path.to_path_buf(), e.kind()))
+}
+
+fn write_source(path: &Path, contents: &str) -> Result<(), NewDayError> {
+    fs::write(path, contents).map_err(|e| NewDayError::Io(path.to_path_buf(), e.kind()))
+}
+
+/// Creates `src/yYYYY/dayNN.rs` from the template, registers it in
+/// `src/yYYYY.rs` and creates an empty input file. The first day of an event
+/// also creates its year module and registers it in `lib.rs`. Returns the
+/// paths that were written.
+pub fn new_day(year: i32, day: i32) -> Result<Vec<PathBuf>, NewDayError> {
+    let year_path = src_dir().join(format!("y{}.rs", year));
+    let module_path = src_dir()
+        .join(format!("y{}", year))
+        .join(format!("day{:02}.rs", day));
+    let lib_path = src_dir().join("lib.rs");
+
+    if module_path.exists() {
+        return Err(NewDayError::AlreadyExists(module_path));
+    }
+
+    let (year_source, lib_registered) = if year_path.exists() {
+        (read_source(&year_path)?, None)
+    } else {
+        let lib_source = read_source(&lib_path)?;
+        if !lib_source.contains(YEARS_START) {
+            return Err(NewDayError::MissingRegistry(YEARS_START, lib_path));
+        }
+        let registered = insert_year(&lib_source, year)
+            .ok_or_else(|| NewDayError::AlreadyRegistered(format!("year {}", year)))?;
+        (render_year(year), Some(registered))
+    };
+    if !year_source.contains(REGISTRY_START) {
+        return Err(NewDayError::MissingRegistry(REGISTRY_START, year_path));
+    }
+    let registered = insert_registration(&year_source, day)
+        .ok_or_else(|| NewDayError::AlreadyRegistered(format!("day {:02} of {}", day, year)))?;
+
+    // an imported example pre-fills the tests
+    let examples = read_examples(year, day).unwrap_or_default();
+    write_new(&module_path, &render_template(day, examples.first()))?;
+    write_source(&year_path, &registered)?;
+    let mut written = vec![module_path, year_path];
+    if let Some(lib_registered) = lib_registered {
+        write_source(&lib_path, &lib_registered)?;
+        written.push(lib_path);
+    }
+
+    // an input that is already there is kept as is
+    let input = input_path(year, day);
+    match write_new(&input, "") {
+        Ok(()) => written.push(input),
+        Err(NewDayError::AlreadyExists(_)) => {}
+        Err(e) => return Err(e),
+    }
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = r#"use crate::runner::register_days;
+
+register_days! {
+    1 => day01::Day01, "Trebuchet?!";
+    9 => day09::Day09, "Mirage Maintenance";
+}
+
+pub fn solve() {}
+"#;
+
+    #[test]
+    fn renders_template() {
+        let result = render_template(12, None);
+
+        assert!(result.contains("pub struct Day12;"));
+        assert!(!result.contains("Day00"));
+    }
+
+    #[test]
+    fn fills_in_imported_example() {
+        let example = ImportedExample {
+            input: String::from("say \"#hi\"\n"),
+            part1: Some(String::from("142")),
+            part2: Some(String::from("x1")),
+        };
+
+        let result = render_template(3, Some(&example));
+
+        assert!(result.contains("const EXAMPLE: &str = r##\"say \"#hi\"\n\"##;"));
+        assert!(result.contains("part1: Some(\"142\"),"));
+        assert!(result.contains("part2: Some(\"x1\"),"));
+        assert!(result.contains("Day03::part1(EXAMPLE);\n        let expected = 142;"));
+        assert!(result.contains("Day03::part2(EXAMPLE);\n        let expected = 0;"));
+    }
+
+    #[test]
+    fn inserts_registration_in_order() {
+        let result = insert_registration(LIB, 3).unwrap();
+
+        assert!(result.contains(
+            r#"    1 => day01::Day01, "Trebuchet?!";
+    3 => day03::Day03, "Day 3";
+    9 => day09::Day09, "Mirage Maintenance";"#
+        ));
+        assert!(result.ends_with("pub fn solve() {}\n"));
+    }
+
+    #[test]
+    fn inserts_registration_last() {
+        let result = insert_registration(LIB, 10).unwrap();
+
+        assert!(result.contains(
+            r#"    9 => day09::Day09, "Mirage Maintenance";
+    10 => day10::Day10, "Day 10";
+}"#
+        ));
+    }
+
+    #[test]
+    fn registers_new_year() {
+        let lib = "register_years! {\n    2023 => y2023;\n}\n";
+        let result = insert_year(lib, 2024).unwrap();
+
+        assert_eq!(
+            result,
+            "register_years! {\n    2023 => y2023;\n    2024 => y2024;\n}\n"
+        );
+        assert_eq!(insert_year(&result, 2023), None);
+
+        let year = insert_registration(&render_year(2024), 1).unwrap();
+        assert!(year.contains("register_days! {\n    1 => day01::Day01, \"Day 1\";\n}\n"));
+    }
+
+    #[test]
+    fn refuses_registered_day() {
+        assert_eq!(insert_registration(LIB, 9), None);
+        assert_eq!(insert_registration("fn main() {}", 9), None);
+    }
+}
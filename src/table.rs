@@ -0,0 +1,23 @@
+/// Prints rows as a left aligned table, the first row is used as the header.
+pub fn print_table<const N: usize>(rows: &[[String; N]]) {
+    let mut widths = [0; N];
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = usize::max(*width, cell.chars().count());
+        }
+    }
+
+    for (idx, row) in rows.iter().enumerate() {
+        let line: Vec<_> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        println!("{}", line.join(" | ").trim_end());
+
+        if idx == 0 {
+            let separator: Vec<_> = widths.iter().map(|&width| "-".repeat(width)).collect();
+            println!("{}", separator.join("-+-"));
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::runner::Solve;
+use crate::runner::{Example, Solve};
 
 pub struct Day00;
 
@@ -11,12 +11,18 @@ impl Solve<i32, i32> for Day00 {
     }
 }
 
+const EXAMPLE: &str = r#""#;
+
+pub const EXAMPLES: &[Example] = &[Example {
+    input: EXAMPLE,
+    part1: None,
+    part2: None,
+}];
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const EXAMPLE: &str = r#""#;
-
     #[test]
     fn parses_example() {
         // todo
@@ -0,0 +1,72 @@
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How often the watched files are checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Modification time of each file, `None` for one that can't be read, so a
+/// file being created or removed also counts as a change.
+pub fn modified(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| fs::metadata(path).and_then(|meta| meta.modified()).ok())
+        .collect()
+}
+
+/// Calls `run` straight away and again every time one of the files changes.
+/// Only returns if `run` asks to stop by returning `false`.
+pub fn watch(paths: &[PathBuf], interval: Duration, mut run: impl FnMut() -> bool) {
+    let mut previous = modified(paths);
+    if !run() {
+        return;
+    }
+
+    loop {
+        thread::sleep(interval);
+        let current = modified(paths);
+        if current != previous {
+            previous = current;
+            if !run() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_modification_times() {
+        let paths = [
+            PathBuf::from("Cargo.toml"),
+            PathBuf::from("./does/not/exist.input"),
+        ];
+        let result = modified(&paths);
+
+        assert!(result[0].is_some());
+        assert_eq!(result[1], None);
+    }
+
+    #[test]
+    fn reruns_on_change() {
+        let path = std::env::temp_dir().join(format!("aoc2023-watch-{}", std::process::id()));
+        let paths = [path.clone()];
+        let mut runs = 0;
+
+        watch(&paths, Duration::from_millis(1), || {
+            runs += 1;
+            match runs {
+                1 => fs::write(&path, "1 2 3\n").unwrap(),
+                2 => fs::remove_file(&path).unwrap(),
+                _ => {}
+            }
+            runs < 3
+        });
+
+        assert_eq!(runs, 3);
+    }
+}
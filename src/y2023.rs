@@ -0,0 +1,15 @@
+//! Advent of Code 2023 solutions.
+
+use crate::runner::register_days;
+
+register_days! {
+    1 => day01::Day01, "Trebuchet?!", generate;
+    2 => day02::Day02, "Cube Conundrum", generate;
+    3 => day03::Day03, "Gear Ratios", generate;
+    4 => day04::Day04, "Scratchcards", generate;
+    5 => day05::Day05, "If You Give A Seed A Fertilizer", generate;
+    6 => day06::Day06, "Wait For It", generate;
+    7 => day07::Day07, "Camel Cards", generate;
+    8 => day08::Day08, "Haunted Wasteland", generate;
+    9 => day09::Day09, "Mirage Maintenance", generate;
+}
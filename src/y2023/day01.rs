@@ -0,0 +1,182 @@
+use crate::generator::{Generated, Rng};
+use crate::runner::{Example, PuzzleError, TrySolve};
+
+const DAY: i32 = 1;
+
+fn first_and_last(numbers: &[u32], line: usize, text: &str) -> Result<u32, PuzzleError> {
+    match (numbers.first(), numbers.last()) {
+        (Some(first), Some(last)) => Ok(first * 10 + last),
+        _ => Err(PuzzleError::new(DAY, line, text, "at least one digit")),
+    }
+}
+
+fn calibration_value(line: usize, text: &str) -> Result<u32, PuzzleError> {
+    let numbers: Vec<_> = text.chars().filter_map(|c| c.to_digit(10)).collect();
+
+    first_and_last(&numbers, line, text)
+}
+
+const TEXT_NUMBERS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+fn parse_digit(text: &str) -> Option<u32> {
+    let first_char = text.chars().next().unwrap();
+
+    if first_char.is_ascii_digit() {
+        return first_char.to_digit(10);
+    } else {
+        for (idx, text_number) in TEXT_NUMBERS.iter().enumerate() {
+            if text.starts_with(text_number) {
+                return idx.try_into().ok();
+            }
+        }
+    }
+    None
+}
+
+fn calibration_value_part2(line: usize, text: &str) -> Result<u32, PuzzleError> {
+    let mut numbers = Vec::new();
+
+    for (idx, _) in text.char_indices() {
+        if let Some(number) = parse_digit(&text[idx..]) {
+            numbers.push(number);
+        }
+    }
+
+    first_and_last(&numbers, line, text)
+}
+
+pub struct Day01;
+
+impl TrySolve<u32, u32> for Day01 {
+    fn try_part1(input: &str) -> Result<u32, PuzzleError> {
+        input
+            .trim()
+            .split('\n')
+            .enumerate()
+            .map(|(idx, text)| calibration_value(idx + 1, text))
+            .sum()
+    }
+    fn try_part2(input: &str) -> Result<u32, PuzzleError> {
+        input
+            .trim()
+            .split('\n')
+            .enumerate()
+            .map(|(idx, text)| calibration_value_part2(idx + 1, text))
+            .sum()
+    }
+}
+
+/// Letters that aren't in any spelled out digit, so they can't form one.
+const FILLER: &[u8] = b"abcdjklmpqy";
+
+fn push_filler(rng: &mut Rng, line: &mut String) {
+    for _ in 0..rng.range(1..=4) {
+        line.push(*rng.pick(FILLER) as char);
+    }
+}
+
+/// Generates `size` lines of filler letters, digits and spelled out digits,
+/// each with at least one digit for part 1. Spelled out digits are always
+/// followed by filler so they never overlap.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut lines = Vec::new();
+    let (mut part1, mut part2) = (0, 0);
+
+    for _ in 0..size.max(1) {
+        let mut line = String::new();
+        let mut digits = Vec::new();
+        let mut all_digits = Vec::new();
+        for _ in 0..rng.range(1..=6) {
+            match rng.range(0..=2) {
+                0 => push_filler(rng, &mut line),
+                1 => {
+                    let digit = rng.range(1..=9) as u32;
+                    line.push(char::from_digit(digit, 10).unwrap());
+                    digits.push(digit);
+                    all_digits.push(digit);
+                }
+                _ => {
+                    let digit = rng.range(1..=9) as usize;
+                    line.push_str(TEXT_NUMBERS[digit]);
+                    push_filler(rng, &mut line);
+                    all_digits.push(digit as u32);
+                }
+            }
+        }
+        if digits.is_empty() {
+            let digit = rng.range(1..=9) as u32;
+            line.push(char::from_digit(digit, 10).unwrap());
+            digits.push(digit);
+            all_digits.push(digit);
+        }
+
+        part1 += digits[0] * 10 + digits[digits.len() - 1];
+        part2 += all_digits[0] * 10 + all_digits[all_digits.len() - 1];
+        lines.push(line);
+    }
+
+    Generated {
+        input: lines.join("\n") + "\n",
+        part1: part1.to_string(),
+        part2: part2.to_string(),
+    }
+}
+
+const EXAMPLE1: &str = r#"1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+"#;
+
+const EXAMPLE2: &str = r#"two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+"#;
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: EXAMPLE1,
+        part1: Some("142"),
+        part2: None,
+    },
+    Example {
+        input: EXAMPLE2,
+        part1: None,
+        part2: Some("281"),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_p1() {
+        let result = Day01::try_part1(EXAMPLE1).unwrap();
+        let expected = 142;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn example_p2() {
+        let result = Day01::try_part2(EXAMPLE2).unwrap();
+        let expected = 281;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn reports_line_without_digits() {
+        let result = Day01::try_part1("1abc2\npqrstuvwx\n");
+        let expected = PuzzleError::new(1, 2, "pqrstuvwx", "at least one digit");
+
+        assert_eq!(result, Err(expected));
+    }
+}
@@ -0,0 +1,227 @@
+use crate::generator::{Generated, Rng};
+use crate::runner::{Example, ParseSolve, PuzzleError};
+
+const DAY: i32 = 2;
+
+pub struct Day02;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+struct ColorSet {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+fn parse_color_set(line: usize, text: &str) -> Result<ColorSet, PuzzleError> {
+    let mut result = ColorSet::default();
+    for reveal in text.split(", ") {
+        let error = |expected| PuzzleError::new(DAY, line, reveal, expected);
+
+        let (number, color) = reveal
+            .split_once(' ')
+            .ok_or_else(|| error("a reveal like `3 blue`"))?;
+        let reveal_value = number
+            .parse::<u32>()
+            .map_err(|_| error("a number of cubes"))?;
+        match color {
+            "red" => result.red = reveal_value,
+            "blue" => result.blue = reveal_value,
+            "green" => result.green = reveal_value,
+            _ => return Err(error("a color (red, green or blue)")),
+        }
+    }
+
+    Ok(result)
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Game {
+    game_id: u32,
+    sets: Vec<ColorSet>,
+}
+
+fn parse_line(line_number: usize, line: &str) -> Result<Game, PuzzleError> {
+    let error = |expected| PuzzleError::new(DAY, line_number, line, expected);
+
+    let (game_id_text, rest) = line
+        .split_once(": ")
+        .ok_or_else(|| error("a line like `Game 1: 3 blue`"))?;
+    let game_id: u32 = game_id_text
+        .strip_prefix("Game ")
+        .and_then(|id| id.parse().ok())
+        .ok_or_else(|| error("a `Game <id>` prefix"))?;
+
+    let sets = rest
+        .split("; ")
+        .map(|set| parse_color_set(line_number, set))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Game { game_id, sets })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Game>, PuzzleError> {
+    input
+        .trim()
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| parse_line(idx + 1, line))
+        .collect()
+}
+
+fn maximum(game: &Game) -> ColorSet {
+    let mut result = ColorSet::default();
+
+    for set in game.sets.iter() {
+        result.red = u32::max(result.red, set.red);
+        result.blue = u32::max(result.blue, set.blue);
+        result.green = u32::max(result.green, set.green);
+    }
+
+    result
+}
+
+fn power(set: &ColorSet) -> u32 {
+    set.red * set.blue * set.green
+}
+
+impl ParseSolve<u32, u32> for Day02 {
+    type Input = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Vec<Game>, PuzzleError> {
+        parse_input(input)
+    }
+    fn solve_part1(games: &Vec<Game>) -> Result<u32, PuzzleError> {
+        let result = games
+            .iter()
+            .filter(|game| {
+                let max_set = maximum(game);
+                max_set.red <= 12 && max_set.green <= 13 && max_set.blue <= 14
+            })
+            .map(|game| game.game_id)
+            .sum();
+
+        Ok(result)
+    }
+    fn solve_part2(games: &Vec<Game>) -> Result<u32, PuzzleError> {
+        let result = games
+            .iter()
+            .map(maximum)
+            .map(|set| power(&set))
+            .sum();
+
+        Ok(result)
+    }
+}
+
+/// Generates `size` games of up to five reveals of up to 15 cubes per color,
+/// so about a third of them are possible.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    const COLORS: [&str; 3] = ["red", "green", "blue"];
+    let mut lines = Vec::new();
+    let (mut part1, mut part2) = (0, 0);
+
+    for game_id in 1..=size.max(1) as u32 {
+        let mut maximum = [0; 3];
+        let mut sets = Vec::new();
+        for _ in 0..rng.range(1..=5) {
+            let mut colors = [0, 1, 2];
+            rng.shuffle(&mut colors);
+            let shown = rng.range(1..=3) as usize;
+
+            let mut reveals = Vec::new();
+            for &color in &colors[..shown] {
+                let count = rng.range(1..=15) as u32;
+                maximum[color] = maximum[color].max(count);
+                reveals.push(format!("{} {}", count, COLORS[color]));
+            }
+            sets.push(reveals.join(", "));
+        }
+
+        let [red, green, blue] = maximum;
+        if red <= 12 && green <= 13 && blue <= 14 {
+            part1 += game_id;
+        }
+        part2 += red * green * blue;
+        lines.push(format!("Game {}: {}", game_id, sets.join("; ")));
+    }
+
+    Generated {
+        input: lines.join("\n") + "\n",
+        part1: part1.to_string(),
+        part2: part2.to_string(),
+    }
+}
+
+const EXAMPLE: &str = r#"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+"#;
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: EXAMPLE,
+        part1: Some("8"),
+        part2: Some("2286"),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_example() {
+        let result = parse_input(EXAMPLE).unwrap().first().unwrap().clone();
+        let expected = Game {
+            game_id: 1,
+            sets: vec![
+                ColorSet {
+                    blue: 3,
+                    red: 4,
+                    green: 0,
+                },
+                ColorSet {
+                    red: 1,
+                    green: 2,
+                    blue: 6,
+                },
+                ColorSet {
+                    green: 2,
+                    red: 0,
+                    blue: 0,
+                },
+            ],
+        };
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn reports_invalid_color() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 blue, 2 purple\n";
+        let result = parse_input(input);
+        let expected = PuzzleError::new(2, 2, "2 purple", "a color (red, green or blue)");
+
+        assert_eq!(result, Err(expected));
+    }
+
+    #[test]
+    fn example_p1() {
+        let games = Day02::parse(EXAMPLE).unwrap();
+        let result = Day02::solve_part1(&games).unwrap();
+        let expected = 8;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn example_p2() {
+        let games = Day02::parse(EXAMPLE).unwrap();
+        let result = Day02::solve_part2(&games).unwrap();
+        let expected = 2286;
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,219 @@
+use crate::generator::{Generated, Rng};
+use crate::runner::{Example, ParseSolve, PuzzleError};
+
+pub struct Day03;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Number {
+    value: u32,
+    start: usize,
+    end: usize,
+    row: usize,
+}
+
+fn is_symbol(ch: char) -> bool {
+    !ch.is_ascii_digit() && ch != '.'
+}
+
+pub struct Engine {
+    symbols: Vec<(usize, usize, char)>,
+    numbers: Vec<Number>,
+}
+
+fn parse_engine(input: &str) -> Engine {
+    let mut symbols = Vec::new();
+    let mut numbers = Vec::new();
+
+    for (row, line) in input.trim().lines().enumerate() {
+        for (col, ch) in line.chars().enumerate() {
+            if is_symbol(ch) {
+                symbols.push((row, col, ch));
+            }
+        }
+
+        let mut number = None;
+        for (col, ch) in line.chars().enumerate() {
+            match (number.take(), !ch.is_ascii_digit()) {
+                (None, true) => {}
+                (None, false) => {
+                    number = Some(Number {
+                        value: ch.to_digit(10).unwrap(),
+                        start: col,
+                        end: col,
+                        row,
+                    });
+                }
+                (Some(value), true) => numbers.push(value),
+                (Some(mut value), false) => {
+                    value.end += 1;
+                    value.value = value.value * 10 + ch.to_digit(10).unwrap();
+
+                    number = Some(value);
+                }
+            }
+        }
+        if let Some(number) = number {
+            numbers.push(number);
+        }
+    }
+
+    Engine { symbols, numbers }
+}
+
+fn is_adjacent(position: &(usize, usize, char), number: &Number) -> bool {
+    (position.0 as isize - number.row as isize).abs() <= 1
+        && position.1 >= number.start.saturating_sub(1)
+        && position.1 <= number.end + 1
+}
+
+impl ParseSolve<u32, u32> for Day03 {
+    type Input = Engine;
+
+    fn parse(input: &str) -> Result<Engine, PuzzleError> {
+        Ok(parse_engine(input))
+    }
+    fn solve_part1(engine: &Engine) -> Result<u32, PuzzleError> {
+        let result = engine
+            .numbers
+            .iter()
+            .filter(|number| {
+                engine
+                    .symbols
+                    .iter()
+                    .any(|symbol| is_adjacent(symbol, number))
+            })
+            .map(|number| number.value)
+            .sum();
+
+        Ok(result)
+    }
+    fn solve_part2(engine: &Engine) -> Result<u32, PuzzleError> {
+        let result = engine
+            .symbols
+            .iter()
+            .filter(|symbol| symbol.2 == '*')
+            .filter_map(|symbol| {
+                let adjacent: Vec<_> = engine
+                    .numbers
+                    .iter()
+                    .filter(|number| is_adjacent(symbol, number))
+                    .collect();
+
+                if adjacent.len() == 2 {
+                    Some(adjacent[0].value * adjacent[1].value)
+                } else {
+                    None
+                }
+            })
+            .sum();
+
+        Ok(result)
+    }
+}
+
+/// Generates a `size` by `size` schematic, at least five columns wide and at
+/// most 140 by 140 like the puzzle input, of numbers of up to three digits
+/// with symbols scattered between them.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    const SYMBOLS: &[u8] = b"*#+$/@%=&-";
+    let (rows, cols) = (size.clamp(1, 140), size.clamp(5, 140));
+    let mut lines = Vec::new();
+    let mut numbers = Vec::new();
+    let mut symbols = Vec::new();
+
+    for row in 0..rows {
+        let mut line = String::new();
+        while line.len() < cols {
+            let col = line.len();
+            let after_number = line.ends_with(|ch: char| ch.is_ascii_digit());
+            let digits = rng.range(1..=3) as usize;
+            if !after_number && col + digits <= cols && rng.chance(25) {
+                let value = rng.range(10u64.pow(digits as u32 - 1)..=10u64.pow(digits as u32) - 1);
+                line.push_str(&value.to_string());
+                numbers.push(Number {
+                    value: value as u32,
+                    start: col,
+                    end: col + digits - 1,
+                    row,
+                });
+            } else if rng.chance(10) {
+                let symbol = *rng.pick(SYMBOLS) as char;
+                line.push(symbol);
+                symbols.push((row, col, symbol));
+            } else {
+                line.push('.');
+            }
+        }
+        lines.push(line);
+    }
+
+    let part1: u32 = numbers
+        .iter()
+        .filter(|number| symbols.iter().any(|symbol| is_adjacent(symbol, number)))
+        .map(|number| number.value)
+        .sum();
+    let part2: u32 = symbols
+        .iter()
+        .filter(|symbol| symbol.2 == '*')
+        .map(|symbol| {
+            let adjacent: Vec<u32> = numbers
+                .iter()
+                .filter(|number| is_adjacent(symbol, number))
+                .map(|number| number.value)
+                .collect();
+            match adjacent[..] {
+                [first, second] => first * second,
+                _ => 0,
+            }
+        })
+        .sum();
+
+    Generated {
+        input: lines.join("\n") + "\n",
+        part1: part1.to_string(),
+        part2: part2.to_string(),
+    }
+}
+
+const EXAMPLE: &str = r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+"#;
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: EXAMPLE,
+        part1: Some("4361"),
+        part2: Some("467835"),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_p1() {
+        let engine = Day03::parse(EXAMPLE).unwrap();
+        let result = Day03::solve_part1(&engine).unwrap();
+        let expected = 4361;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn example_p2() {
+        let engine = Day03::parse(EXAMPLE).unwrap();
+        let result = Day03::solve_part2(&engine).unwrap();
+        let expected = 467835;
+
+        assert_eq!(result, expected);
+    }
+}
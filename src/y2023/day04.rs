@@ -0,0 +1,191 @@
+use std::collections::HashSet;
+
+use crate::generator::{Generated, Rng};
+use crate::runner::{Example, ParseSolve, PuzzleError};
+
+const DAY: i32 = 4;
+
+pub struct Day04;
+
+#[derive(Debug)]
+pub struct Card {
+    left: HashSet<i32>,
+    right: HashSet<i32>,
+}
+
+fn parse_numbers(line: usize, text: &str) -> Result<HashSet<i32>, PuzzleError> {
+    text.split_ascii_whitespace()
+        .map(|s| {
+            s.parse()
+                .map_err(|_| PuzzleError::new(DAY, line, s, "a number"))
+        })
+        .collect()
+}
+
+fn parse_card(line_number: usize, line: &str) -> Result<Card, PuzzleError> {
+    let error = |expected| PuzzleError::new(DAY, line_number, line, expected);
+
+    let (_, rest) = line
+        .split_once(": ")
+        .ok_or_else(|| error("a `Card <id>: ` prefix"))?;
+
+    let (left, right) = rest
+        .split_once(" | ")
+        .ok_or_else(|| error("numbers separated by ` | `"))?;
+
+    Ok(Card {
+        left: parse_numbers(line_number, left)?,
+        right: parse_numbers(line_number, right)?,
+    })
+}
+
+fn parse_cards(input: &str) -> Result<Vec<Card>, PuzzleError> {
+    input
+        .trim()
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| parse_card(idx + 1, line))
+        .collect()
+}
+
+fn calculate_winning_numbers(card: &Card) -> usize {
+    card.right.intersection(&card.left).count()
+}
+
+impl ParseSolve<i32, i32> for Day04 {
+    type Input = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Vec<Card>, PuzzleError> {
+        parse_cards(input)
+    }
+    fn solve_part1(cards: &Vec<Card>) -> Result<i32, PuzzleError> {
+        let result = cards
+            .iter()
+            .map(calculate_winning_numbers)
+            .filter(|&matches| matches > 0)
+            .map(|matches| 2i32.pow(matches.saturating_sub(1) as u32))
+            .sum();
+
+        Ok(result)
+    }
+    fn solve_part2(cards: &Vec<Card>) -> Result<i32, PuzzleError> {
+        let matching_count: Vec<_> = cards.iter().map(calculate_winning_numbers).collect();
+
+        let mut card_count = vec![1; cards.len()];
+
+        for (idx, count) in matching_count.into_iter().enumerate() {
+            let times = card_count[idx];
+
+            for j in 0..count {
+                card_count[idx + j + 1] += times;
+            }
+        }
+
+        Ok(card_count.into_iter().sum())
+    }
+}
+
+/// Generates `size` cards of ten winning and twenty five held numbers. Cards
+/// stop matching once they have so many copies that part 2 would overflow.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let count = size.max(1);
+    let mut copies = vec![1; count];
+    let mut lines = Vec::new();
+    let (mut part1, mut part2) = (0, 0);
+
+    for idx in 0..count {
+        let mut numbers: Vec<i32> = (1..=99).collect();
+        rng.shuffle(&mut numbers);
+        let mut matches = (rng.range(0..=10) as usize).min(count - idx - 1);
+        if copies[idx] > 1_000 {
+            matches = 0;
+        }
+
+        let winning = &numbers[..10];
+        let mut held = numbers[10 - matches..35 - matches].to_vec();
+        rng.shuffle(&mut held);
+
+        if matches > 0 {
+            part1 += 1 << (matches - 1);
+        }
+        part2 += copies[idx];
+        for next in idx + 1..=idx + matches {
+            copies[next] += copies[idx];
+        }
+
+        let format = |numbers: &[i32]| {
+            let numbers: Vec<_> = numbers.iter().map(|n| format!("{:>2}", n)).collect();
+            numbers.join(" ")
+        };
+        lines.push(format!(
+            "Card {:>3}: {} | {}",
+            idx + 1,
+            format(winning),
+            format(&held)
+        ));
+    }
+
+    Generated {
+        input: lines.join("\n") + "\n",
+        part1: part1.to_string(),
+        part2: part2.to_string(),
+    }
+}
+
+const EXAMPLE: &str = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+"#;
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: EXAMPLE,
+        part1: Some("13"),
+        part2: Some("30"),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_missing_separators() {
+        let result = parse_cards("Card 1: 1 2 | 1 2\nCard 2 1 2 | 1 2\n").err();
+        let expected = PuzzleError::new(4, 2, "Card 2 1 2 | 1 2", "a `Card <id>: ` prefix");
+        assert_eq!(result, Some(expected));
+
+        let result = parse_cards("Card 1: 1 2 1 2\n").err();
+        let expected = PuzzleError::new(4, 1, "Card 1: 1 2 1 2", "numbers separated by ` | `");
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn reports_invalid_number() {
+        let result = parse_cards("Card 1: 1 2 | 1 2\nCard 2: 1 x | 1 2\n").err();
+        let expected = PuzzleError::new(4, 2, "x", "a number");
+
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn example_p1() {
+        let cards = Day04::parse(EXAMPLE).unwrap();
+        let result = Day04::solve_part1(&cards).unwrap();
+        let expected = 13;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn example_p2() {
+        let cards = Day04::parse(EXAMPLE).unwrap();
+        let result = Day04::solve_part2(&cards).unwrap();
+        let expected = 30;
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,393 @@
+use std::collections::HashSet;
+
+use crate::generator::{Generated, Rng};
+use crate::runner::{Example, ParseSolve, PuzzleError};
+
+const DAY: i32 = 5;
+
+pub struct Day05;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash)]
+struct Range {
+    start: u64,
+    end: u64,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord)]
+struct Map {
+    src: Range,
+    dst: Range,
+}
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: Vec<Vec<Map>>,
+}
+
+fn parse_number(line: usize, text: &str) -> Result<u64, PuzzleError> {
+    text.parse()
+        .map_err(|_| PuzzleError::new(DAY, line, text, "a number"))
+}
+
+fn parse_map(line_number: usize, line: &str) -> Result<Map, PuzzleError> {
+    let numbers = line
+        .split_ascii_whitespace()
+        .map(|s| parse_number(line_number, s))
+        .collect::<Result<Vec<_>, _>>()?;
+    let [dst_start, src_start, len] = numbers[..] else {
+        return Err(PuzzleError::new(
+            DAY,
+            line_number,
+            line,
+            "three numbers: destination, source and length",
+        ));
+    };
+
+    let src = Range {
+        start: src_start,
+        end: src_start + len,
+    };
+    let dst = Range {
+        start: dst_start,
+        end: dst_start + len,
+    };
+
+    Ok(Map { src, dst })
+}
+
+fn parse_input(input: &str) -> Result<Almanac, PuzzleError> {
+    let mut lines = input
+        .trim()
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line));
+
+    let (line_number, seeds) = lines.next().unwrap_or((1, ""));
+    let seeds = seeds
+        .strip_prefix("seeds: ")
+        .ok_or_else(|| PuzzleError::new(DAY, line_number, seeds, "a `seeds: ` line"))?
+        .split_ascii_whitespace()
+        .map(|s| parse_number(line_number, s))
+        .collect::<Result<Vec<_>, _>>()?;
+    if seeds.is_empty() {
+        return Err(PuzzleError::new(DAY, line_number, "", "at least one seed"));
+    }
+
+    let mut maps: Vec<Vec<Map>> = Vec::new();
+    for (line_number, line) in lines {
+        if line.is_empty() {
+            continue;
+        }
+        if line.ends_with(" map:") {
+            maps.push(Vec::new());
+            continue;
+        }
+
+        let map = parse_map(line_number, line)?;
+        maps.last_mut()
+            .ok_or_else(|| PuzzleError::new(DAY, line_number, line, "a `<name> map:` header"))?
+            .push(map);
+    }
+
+    Ok(Almanac { seeds, maps })
+}
+
+fn find_next(position: u64, maps: &[Map]) -> u64 {
+    for map in maps {
+        if position >= map.src.start && position < map.src.end {
+            let diff = position - map.src.start;
+            return map.dst.start + diff;
+        }
+    }
+
+    position
+}
+
+fn find_location(start: u64, almanac: &Almanac) -> u64 {
+    let mut position = start;
+
+    for placement in almanac.maps.iter() {
+        position = find_next(position, placement);
+    }
+
+    position
+}
+
+fn range_intersection(mut range1: Range, mut range2: Range) -> Option<Range> {
+    if range1.start > range2.start {
+        std::mem::swap(&mut range1, &mut range2);
+    }
+
+    if range2.start >= range1.end {
+        None
+    } else {
+        Some(Range {
+            start: range2.start,
+            end: range1.end.min(range2.end),
+        })
+    }
+}
+
+/// removes range2 from range1
+/// range2 intersecting range1 is implied maybe rework this later
+fn subtract_range(range1: Range, range2: Range) -> Vec<Range> {
+    let mut result = Vec::new();
+
+    let left = Range {
+        start: range1.start,
+        end: range2.start,
+    };
+    let right = Range {
+        start: range2.end,
+        end: range1.end,
+    };
+
+    if left.start != left.end {
+        result.push(left);
+    }
+
+    if right.start != right.end {
+        result.push(right)
+    }
+
+    result
+}
+
+fn find_next_ranges(start: HashSet<Range>, maps: &[Map]) -> HashSet<Range> {
+    let mut input_ranges = start;
+    let mut output_ranges = HashSet::new();
+
+    for map in maps {
+        for current_range in input_ranges.clone() {
+            if let Some(range) = range_intersection(current_range, map.src) {
+                let diff_left = range.start - map.src.start;
+                let diff_right = range.end - map.src.start;
+
+                let result_left = map.dst.start + diff_left;
+                let result_right = map.dst.start + diff_right;
+
+                let result = Range {
+                    start: result_left,
+                    end: result_right,
+                };
+
+                for excess in subtract_range(current_range, range) {
+                    input_ranges.insert(excess);
+                }
+                input_ranges.remove(&current_range);
+                output_ranges.insert(result);
+            }
+        }
+    }
+
+    for excess in input_ranges {
+        output_ranges.insert(excess);
+    }
+
+    output_ranges
+}
+
+fn find_locations_ranged(start: Range, almanac: &Almanac) -> HashSet<Range> {
+    let mut positions = HashSet::new();
+    positions.insert(start);
+
+    for placement in almanac.maps.iter() {
+        positions = find_next_ranges(positions, placement);
+    }
+
+    positions
+}
+
+impl ParseSolve<u64, u64> for Day05 {
+    type Input = Almanac;
+
+    fn parse(input: &str) -> Result<Almanac, PuzzleError> {
+        parse_input(input)
+    }
+    fn solve_part1(almanac: &Almanac) -> Result<u64, PuzzleError> {
+        let result = almanac
+            .seeds
+            .iter()
+            .map(|&seed| find_location(seed, almanac))
+            .min()
+            .unwrap();
+
+        Ok(result)
+    }
+    fn solve_part2(almanac: &Almanac) -> Result<u64, PuzzleError> {
+        if !almanac.seeds.len().is_multiple_of(2) {
+            let seeds: Vec<_> = almanac.seeds.iter().map(u64::to_string).collect();
+            return Err(PuzzleError::new(
+                DAY,
+                1,
+                &format!("seeds: {}", seeds.join(" ")),
+                "seed ranges as pairs of start and length",
+            ));
+        }
+
+        let result = almanac
+            .seeds
+            .chunks_exact(2)
+            .map(|slice| Range {
+                start: slice[0],
+                end: slice[0] + slice[1],
+            })
+            .flat_map(|seed_range| find_locations_ranged(seed_range, almanac))
+            .min()
+            .unwrap()
+            .start;
+
+        Ok(result)
+    }
+}
+
+/// Largest size to generate an almanac for, so the numbers stay small enough
+/// to check part 2 seed by seed.
+const MAX_SIZE: usize = 10_000;
+
+/// Generates an almanac of numbers below `100 * (size + 1)`, with `size` up
+/// to `MAX_SIZE`, up to ten seed ranges and seven maps of non-overlapping
+/// source ranges.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    const NAMES: [&str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+    let size = size.min(MAX_SIZE);
+    let domain = 100 * size as u64 + 100;
+
+    let mut seeds = Vec::new();
+    for _ in 0..(1 + size / 4).clamp(1, 10) {
+        seeds.push(rng.range(0..=domain - 1));
+        seeds.push(rng.range(1..=domain / 10));
+    }
+
+    let mut sections = Vec::new();
+    let mut maps = Vec::new();
+    for name in NAMES {
+        let mut lines = vec![format!("{} map:", name)];
+        let mut ranges = Vec::new();
+        let mut position = 0;
+        for _ in 0..rng.range(1..=4) {
+            let start = position + rng.range(0..=domain / 8);
+            let len = rng.range(1..=domain / 8);
+            if start + len > domain {
+                break;
+            }
+            let dst = rng.range(0..=domain);
+            lines.push(format!("{} {} {}", dst, start, len));
+            ranges.push((dst, start, len));
+            position = start + len;
+        }
+        sections.push(lines.join("\n"));
+        maps.push(ranges);
+    }
+
+    let location = |seed: u64| {
+        maps.iter().fold(seed, |position, ranges| {
+            ranges
+                .iter()
+                .find(|&&(_, start, len)| (start..start + len).contains(&position))
+                .map_or(position, |&(dst, start, _)| dst + position - start)
+        })
+    };
+    let part1 = seeds.iter().map(|&seed| location(seed)).min().unwrap();
+    let part2 = seeds
+        .chunks_exact(2)
+        .flat_map(|pair| pair[0]..pair[0] + pair[1])
+        .map(location)
+        .min()
+        .unwrap();
+
+    let seeds: Vec<_> = seeds.iter().map(u64::to_string).collect();
+    Generated {
+        input: format!("seeds: {}\n\n{}\n", seeds.join(" "), sections.join("\n\n")),
+        part1: part1.to_string(),
+        part2: part2.to_string(),
+    }
+}
+
+const EXAMPLE: &str = r#"seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+"#;
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: EXAMPLE,
+        part1: Some("35"),
+        part2: Some("46"),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_incomplete_map() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50\n";
+        let result = parse_input(input).err();
+        let expected = PuzzleError::new(
+            5,
+            5,
+            "52 50",
+            "three numbers: destination, source and length",
+        );
+
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn example_p1() {
+        let almanac = Day05::parse(EXAMPLE).unwrap();
+        let result = Day05::solve_part1(&almanac).unwrap();
+        let expected = 35;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn example_p2() {
+        let almanac = Day05::parse(EXAMPLE).unwrap();
+        let result = Day05::solve_part2(&almanac).unwrap();
+        let expected = 46;
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,172 @@
+use crate::generator::{Generated, Rng};
+use crate::runner::{Example, PuzzleError, TrySolve};
+
+const DAY: i32 = 6;
+
+pub struct Day06;
+
+struct Race {
+    time: u64,
+    distance: u64,
+}
+
+/// Splits the input into the values of the `Time:` and `Distance:` lines.
+fn split_lines(input: &str) -> Result<[&str; 2], PuzzleError> {
+    let mut lines = input.trim().split('\n');
+
+    let mut values = [""; 2];
+    for (idx, prefix) in ["Time:", "Distance:"].into_iter().enumerate() {
+        let line = lines.next().unwrap_or("");
+        values[idx] = line.strip_prefix(prefix).ok_or_else(|| {
+            PuzzleError::new(DAY, idx + 1, line, &format!("a `{}` line", prefix))
+        })?;
+    }
+
+    Ok(values)
+}
+
+fn parse_numbers(line: usize, text: &str) -> Result<Vec<u64>, PuzzleError> {
+    text.split_whitespace()
+        .map(|s| {
+            s.parse()
+                .map_err(|_| PuzzleError::new(DAY, line, s, "a number"))
+        })
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Race>, PuzzleError> {
+    let [times, distances] = split_lines(input)?;
+    let times = parse_numbers(1, times)?;
+    let distances = parse_numbers(2, distances)?;
+
+    if times.len() != distances.len() {
+        return Err(PuzzleError::new(
+            DAY,
+            2,
+            input.trim().lines().nth(1).unwrap_or(""),
+            "as many distances as times",
+        ));
+    }
+
+    let races = times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Race { time, distance })
+        .collect();
+
+    Ok(races)
+}
+
+fn simulate_race(time_remaining: u64, speed: u64) -> u64 {
+    speed * time_remaining
+}
+
+fn calculate_record_breaks(race: Race) -> u64 {
+    let mut result = 0;
+    for time_spend_holding in 0..=race.time {
+        let time_remaining = race.time - time_spend_holding;
+        let distance = simulate_race(time_remaining, time_spend_holding);
+        if distance > race.distance {
+            result += 1;
+        }
+    }
+    result
+}
+
+fn parse_input_kernel_fix(input: &str) -> Result<Race, PuzzleError> {
+    let [time, distance] = split_lines(input)?;
+
+    let parse = |line, text: &str| {
+        let mut s = String::from(text);
+        s.retain(|ch| !ch.is_whitespace());
+        s.parse()
+            .map_err(|_| PuzzleError::new(DAY, line, text.trim(), "a number"))
+    };
+
+    Ok(Race {
+        time: parse(1, time)?,
+        distance: parse(2, distance)?,
+    })
+}
+impl TrySolve<u64, u64> for Day06 {
+    fn try_part1(input: &str) -> Result<u64, PuzzleError> {
+        let races = parse_input(input)?;
+        Ok(races.into_iter().map(calculate_record_breaks).product())
+    }
+    fn try_part2(input: &str) -> Result<u64, PuzzleError> {
+        let race = parse_input_kernel_fix(input)?;
+        Ok(calculate_record_breaks(race))
+    }
+}
+
+/// Generates up to three races, `size` being their number, each of which can
+/// be won. Times stay below a hundred to keep part 2's single race short.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let ways = |time: u64, distance: u64| {
+        (0..=time)
+            .filter(|&holding| holding * (time - holding) > distance)
+            .count() as u64
+    };
+
+    let mut times = Vec::new();
+    let mut distances = Vec::new();
+    for _ in 0..size.clamp(1, 3) {
+        let time = rng.range(7..=99);
+        let record = (time / 2) * (time - time / 2);
+        times.push(time.to_string());
+        distances.push(rng.range(0..=record - 1).to_string());
+    }
+
+    let part1: u64 = times
+        .iter()
+        .zip(&distances)
+        .map(|(time, distance)| ways(time.parse().unwrap(), distance.parse().unwrap()))
+        .product();
+    let part2 = ways(
+        times.concat().parse().unwrap(),
+        distances.concat().parse().unwrap(),
+    );
+
+    Generated {
+        input: format!(
+            "Time:      {}\nDistance:  {}\n",
+            times.join("  "),
+            distances.join("  ")
+        ),
+        part1: part1.to_string(),
+        part2: part2.to_string(),
+    }
+}
+
+const EXAMPLE: &str = r#"Time:      7  15   30
+Distance:  9  40  200
+"#;
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: EXAMPLE,
+        part1: Some("288"),
+        part2: Some("71503"),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_p1() {
+        let result = Day06::try_part1(EXAMPLE).unwrap();
+        let expected = 288;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn example_p2() {
+        let result = Day06::try_part2(EXAMPLE).unwrap();
+        let expected = 71503;
+
+        assert_eq!(result, expected);
+    }
+}
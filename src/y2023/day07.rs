@@ -0,0 +1,351 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use crate::generator::{Generated, Rng};
+use crate::runner::{Example, ParseSolve, PuzzleError};
+
+const DAY: i32 = 7;
+
+pub struct Day07;
+
+#[derive(Clone)]
+pub struct Hand {
+    hand: String,
+    counts: [usize; 13],
+    bid: u32,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl Hand {
+    fn get_type(&self) -> HandType {
+        let counts: [usize; 5] = [
+            self.counts.iter().filter(|&&v| v == 1).count(),
+            self.counts.iter().filter(|&&v| v == 2).count(),
+            self.counts.iter().filter(|&&v| v == 3).count(),
+            self.counts.iter().filter(|&&v| v == 4).count(),
+            self.counts.iter().filter(|&&v| v == 5).count(),
+        ];
+
+        Hand::calculate_hand(counts)
+    }
+
+    fn calculate_hand(counts: [usize; 5]) -> HandType {
+        match counts {
+            [_, _, _, _, 1] => HandType::FiveOfAKind,
+            [_, _, _, 1, 0] => HandType::FourOfAKind,
+            [_, 1, 1, 0, 0] => HandType::FullHouse,
+            [_, _, 1, 0, 0] => HandType::ThreeOfAKind,
+            [_, 2, 0, 0, 0] => HandType::TwoPair,
+            [_, 1, 0, 0, 0] => HandType::OnePair,
+            [_, 0, 0, 0, 0] => HandType::HighCard,
+            _ => panic!("Unexpected hand {:?}", counts),
+        }
+    }
+
+    fn get_type_wildcard(&self) -> HandType {
+        let jokers = self.counts[9];
+
+        let mut counts_without_joker = self.counts;
+        counts_without_joker[9] = 0;
+
+        *counts_without_joker.iter_mut().max().unwrap() += jokers;
+
+        let counts: [usize; 5] = [
+            counts_without_joker.iter().filter(|&&v| v == 1).count(),
+            counts_without_joker.iter().filter(|&&v| v == 2).count(),
+            counts_without_joker.iter().filter(|&&v| v == 3).count(),
+            counts_without_joker.iter().filter(|&&v| v == 4).count(),
+            counts_without_joker.iter().filter(|&&v| v == 5).count(),
+        ];
+
+        Hand::calculate_hand(counts)
+    }
+}
+
+fn get_card_strength(ch: char) -> usize {
+    match ch {
+        'A' => 12,
+        'K' => 11,
+        'Q' => 10,
+        'J' => 9,
+        'T' => 8,
+        '9' => 7,
+        '8' => 6,
+        '7' => 5,
+        '6' => 4,
+        '5' => 3,
+        '4' => 2,
+        '3' => 1,
+        '2' => 0,
+        _ => panic!("Unexpected card"),
+    }
+}
+
+fn get_card_strength_wildcard(ch: char) -> usize {
+    if ch == 'J' {
+        0
+    } else {
+        get_card_strength(ch) + 1
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get_type().cmp(&other.get_type()).then_with(|| {
+            let self_hand: Vec<_> = self.hand.chars().collect();
+            let other_hand: Vec<_> = other.hand.chars().collect();
+            for idx in 0..5 {
+                let self_strength = get_card_strength(self_hand[idx]);
+                let other_strength = get_card_strength(other_hand[idx]);
+
+                let comparison = self_strength.cmp(&other_strength);
+                if comparison != Ordering::Equal {
+                    return comparison;
+                }
+            }
+
+            Ordering::Equal
+        })
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for Hand {}
+
+fn cmp_wildcard(left: &Hand, right: &Hand) -> Ordering {
+    left.get_type_wildcard()
+        .cmp(&right.get_type_wildcard())
+        .then_with(|| {
+            let left_hand: Vec<_> = left.hand.chars().collect();
+            let right_hand: Vec<_> = right.hand.chars().collect();
+            for idx in 0..5 {
+                let left_strength = get_card_strength_wildcard(left_hand[idx]);
+                let right_strength = get_card_strength_wildcard(right_hand[idx]);
+
+                let comparison = left_strength.cmp(&right_strength);
+                if comparison != Ordering::Equal {
+                    return comparison;
+                }
+            }
+
+            Ordering::Equal
+        })
+}
+
+const CARDS: &str = "23456789TJQKA";
+
+fn parse_hand(line_number: usize, line: &str) -> Result<Hand, PuzzleError> {
+    let error = |text, expected| PuzzleError::new(DAY, line_number, text, expected);
+
+    let (hand, bid) = line
+        .split_once(' ')
+        .ok_or_else(|| error(line, "a hand and a bid separated by a space"))?;
+
+    if hand.chars().count() != 5 || !hand.chars().all(|ch| CARDS.contains(ch)) {
+        return Err(error(hand, "five cards from `23456789TJQKA`"));
+    }
+
+    let mut counts = [0; 13];
+
+    for (idx, ch) in CARDS.chars().enumerate() {
+        counts[idx] = hand.chars().filter(|&hand_ch| ch == hand_ch).count();
+    }
+
+    Ok(Hand {
+        hand: String::from(hand),
+        bid: bid.parse().map_err(|_| error(bid, "a bid"))?,
+        counts,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Hand>, PuzzleError> {
+    input
+        .trim()
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| parse_hand(idx + 1, line))
+        .collect()
+}
+
+impl ParseSolve<u32, u32> for Day07 {
+    type Input = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Vec<Hand>, PuzzleError> {
+        parse_input(input)
+    }
+    fn solve_part1(hands: &Vec<Hand>) -> Result<u32, PuzzleError> {
+        let mut hands = hands.clone();
+
+        hands.sort();
+
+        let result = hands
+            .into_iter()
+            .enumerate()
+            .map(|(idx, hand)| (idx as u32 + 1) * hand.bid)
+            .sum();
+
+        Ok(result)
+    }
+    fn solve_part2(hands: &Vec<Hand>) -> Result<u32, PuzzleError> {
+        let mut hands = hands.clone();
+
+        hands.sort_by(cmp_wildcard);
+
+        let result = hands
+            .into_iter()
+            .enumerate()
+            .map(|(idx, hand)| (idx as u32 + 1) * hand.bid)
+            .sum();
+
+        Ok(result)
+    }
+}
+
+/// The strength of a hand: its card counts from most to least common, then
+/// each card's position in `order`. Jokers join the most common card.
+fn hand_strength(hand: &str, order: &str, jokers: bool) -> (Vec<usize>, Vec<usize>) {
+    let mut counts = Vec::new();
+    for card in order.chars() {
+        let count = hand.chars().filter(|&ch| ch == card).count();
+        if count > 0 && !(jokers && card == 'J') {
+            counts.push(count);
+        }
+    }
+    counts.sort_by(|a, b| b.cmp(a));
+    if jokers {
+        let jokers = hand.chars().filter(|&ch| ch == 'J').count();
+        match counts.first_mut() {
+            Some(most) => *most += jokers,
+            None => counts.push(jokers),
+        }
+    }
+
+    let cards = hand.chars().map(|ch| order.find(ch).unwrap()).collect();
+    (counts, cards)
+}
+
+/// Most hands to generate, so the winnings of bids of up to a thousand fit in
+/// the `u32` answers.
+const MAX_HANDS: usize = 2900;
+
+/// Generates `size` distinct hands, up to `MAX_HANDS`, spread over all hand
+/// types with bids of up to a thousand.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    const PATTERNS: [&[usize]; 7] = [
+        &[5],
+        &[4, 1],
+        &[3, 2],
+        &[3, 1, 1],
+        &[2, 2, 1],
+        &[2, 1, 1, 1],
+        &[1, 1, 1, 1, 1],
+    ];
+    let cards: Vec<char> = CARDS.chars().collect();
+    let count = size.clamp(1, MAX_HANDS);
+
+    let mut seen = HashSet::new();
+    let mut hands = Vec::new();
+    while hands.len() < count {
+        let mut shuffled = cards.clone();
+        rng.shuffle(&mut shuffled);
+        let mut hand = Vec::new();
+        for (&card, &repeat) in shuffled.iter().zip(*rng.pick(&PATTERNS)) {
+            hand.extend(std::iter::repeat_n(card, repeat));
+        }
+        rng.shuffle(&mut hand);
+
+        let hand: String = hand.into_iter().collect();
+        if seen.insert(hand.clone()) {
+            hands.push((hand, rng.range(1..=1000) as u32));
+        }
+    }
+
+    let winnings = |order: &str, jokers: bool| -> u32 {
+        let mut ranked = hands.clone();
+        ranked.sort_by_cached_key(|(hand, _)| hand_strength(hand, order, jokers));
+        ranked
+            .iter()
+            .enumerate()
+            .map(|(idx, (_, bid))| (idx as u32 + 1) * bid)
+            .sum()
+    };
+    let part1 = winnings(CARDS, false);
+    let part2 = winnings("J23456789TQKA", true);
+
+    let lines: Vec<_> = hands
+        .iter()
+        .map(|(hand, bid)| format!("{} {}", hand, bid))
+        .collect();
+    Generated {
+        input: lines.join("\n") + "\n",
+        part1: part1.to_string(),
+        part2: part2.to_string(),
+    }
+}
+
+const EXAMPLE: &str = r#"32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+"#;
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: EXAMPLE,
+        part1: Some("6440"),
+        part2: Some("5905"),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_invalid_hand() {
+        let result = parse_input("32T3K 765\nT55X5 684\n").err();
+        let expected = PuzzleError::new(7, 2, "T55X5", "five cards from `23456789TJQKA`");
+
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn example_p1() {
+        let hands = Day07::parse(EXAMPLE).unwrap();
+        let result = Day07::solve_part1(&hands).unwrap();
+        let expected = 6440;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn example_p2() {
+        let hands = Day07::parse(EXAMPLE).unwrap();
+        let result = Day07::solve_part2(&hands).unwrap();
+        let expected = 5905;
+
+        assert_eq!(result, expected);
+    }
+}
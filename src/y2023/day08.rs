@@ -0,0 +1,307 @@
+use std::collections::HashMap;
+
+use crate::generator::{Generated, Rng};
+use crate::runner::{Example, ParseSolve, PuzzleError};
+
+const DAY: i32 = 8;
+
+pub struct Day08;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Instruction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+pub struct Maps {
+    instructions: Vec<Instruction>,
+    network: HashMap<String, (String, String)>,
+}
+
+fn parse_node(line_number: usize, line: &str) -> Result<(String, (String, String)), PuzzleError> {
+    let parse = || {
+        let (node, leftright) = line.split_once(" = ")?;
+        let (left, right) = leftright.split_once(", ")?;
+        let left = left.strip_prefix('(')?;
+        let right = right.strip_suffix(')')?;
+
+        Some((node.to_string(), (left.to_string(), right.to_string())))
+    };
+
+    parse().ok_or_else(|| PuzzleError::new(DAY, line_number, line, "a node like `AAA = (BBB, CCC)`"))
+}
+
+fn parse_maps(input: &str) -> Result<Maps, PuzzleError> {
+    let mut lines = input.trim().lines().enumerate();
+
+    let (_, instructions) = lines.next().unwrap_or((0, ""));
+    let instructions = instructions
+        .trim()
+        .chars()
+        .map(|ch| match ch {
+            'L' => Ok(Instruction::Left),
+            'R' => Ok(Instruction::Right),
+            _ => Err(PuzzleError::new(
+                DAY,
+                1,
+                instructions,
+                "instructions made of `L` and `R`",
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if instructions.is_empty() {
+        return Err(PuzzleError::new(DAY, 1, "", "at least one instruction"));
+    }
+
+    let nodes = lines
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| Ok((idx + 1, line, parse_node(idx + 1, line.trim())?)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let network: HashMap<_, _> = nodes
+        .iter()
+        .map(|(_, _, (node, leftright))| (node.clone(), leftright.clone()))
+        .collect();
+
+    // every node has to lead somewhere, otherwise walking the network fails
+    for (line_number, line, (_, (left, right))) in nodes.iter() {
+        if !network.contains_key(left) || !network.contains_key(right) {
+            return Err(PuzzleError::new(
+                DAY,
+                *line_number,
+                line,
+                "only references to nodes in the network",
+            ));
+        }
+    }
+
+    Ok(Maps {
+        instructions,
+        network,
+    })
+}
+
+fn cost_to_finish(maps: &Maps, start: &str, goal: impl Fn(&str) -> bool) -> usize {
+    let mut current = start.to_string();
+    let mut instruction = maps.instructions.iter().cycle().enumerate();
+    loop {
+        let (step, instruction) = instruction.next().unwrap();
+        if goal(&current) {
+            return step;
+        }
+        let node = maps.network.get(&current).unwrap();
+        if instruction == &Instruction::Left {
+            current = node.0.clone();
+        } else {
+            current = node.1.clone();
+        }
+    }
+}
+
+fn gcd(x: usize, y: usize) -> usize {
+    let mut x = x;
+    let mut y = y;
+    while y != 0 {
+        let t = y;
+        y = x % y;
+        x = t;
+    }
+    x
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    a * b / gcd(a, b)
+}
+
+/// The network starts on the third line, after the instructions.
+fn missing_start(expected: &str) -> PuzzleError {
+    PuzzleError::new(DAY, 3, "", expected)
+}
+
+impl ParseSolve<usize, usize> for Day08 {
+    type Input = Maps;
+
+    fn parse(input: &str) -> Result<Maps, PuzzleError> {
+        parse_maps(input)
+    }
+    fn solve_part1(maps: &Maps) -> Result<usize, PuzzleError> {
+        if !maps.network.contains_key("AAA") {
+            return Err(missing_start("a node named `AAA`"));
+        }
+        Ok(cost_to_finish(maps, "AAA", |s| s == "ZZZ"))
+    }
+    fn solve_part2(maps: &Maps) -> Result<usize, PuzzleError> {
+        let starts: Vec<_> = maps
+            .network
+            .keys()
+            .filter(|s| s.ends_with('A'))
+            .cloned()
+            .collect();
+
+        let costs: Vec<usize> = starts
+            .iter()
+            .map(|s| cost_to_finish(maps, s, |s| s.ends_with('Z')))
+            .collect();
+
+        costs
+            .into_iter()
+            .reduce(lcm)
+            .ok_or_else(|| missing_start("a node ending in `A`"))
+    }
+}
+
+/// Generates a network of up to six ghosts, `size` being their number, with
+/// `10 + 2 * size` instructions, at most 300. Every ghost walks a chain of a
+/// distinct prime number of rounds of the instructions to its end node, which
+/// leads back to the start of the chain, so every cycle is aligned with the
+/// instructions like in the puzzle input.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let rounds = (10 + 2 * size).min(300);
+    let instructions: Vec<char> = (0..rounds)
+        .map(|_| if rng.chance(50) { 'L' } else { 'R' })
+        .collect();
+    let ghosts = size.clamp(1, 6);
+    let mut primes = [2, 3, 5, 7, 11, 13];
+    rng.shuffle(&mut primes);
+
+    let letters: Vec<char> = ('A'..='Z').collect();
+    let mut names = Vec::new();
+    let mut prefixes = Vec::new();
+    for &first in &letters {
+        for &second in &letters {
+            prefixes.push(format!("{}{}", first, second));
+            for &third in &letters[1..25] {
+                names.push(format!("{}{}{}", first, second, third));
+            }
+        }
+    }
+    prefixes.retain(|prefix| prefix != "AA" && prefix != "ZZ");
+    rng.shuffle(&mut names);
+    rng.shuffle(&mut prefixes);
+
+    let mut nodes = Vec::new();
+    for (ghost, &prime) in primes[..ghosts].iter().enumerate() {
+        let (start, end) = match ghost {
+            0 => (String::from("AAA"), String::from("ZZZ")),
+            _ => (format!("{}A", prefixes[ghost]), format!("{}Z", prefixes[ghost])),
+        };
+
+        let length = prime * rounds;
+        let mut chain = vec![start];
+        chain.extend(names.drain(..length - 1));
+        chain.push(end);
+
+        for (idx, node) in chain[..length].iter().enumerate() {
+            let next = &chain[idx + 1];
+            let other = &chain[rng.index(length)];
+            let (left, right) = match instructions[idx % rounds] {
+                'L' => (next, other),
+                _ => (other, next),
+            };
+            nodes.push(format!("{} = ({}, {})", node, left, right));
+        }
+        nodes.push(format!("{} = ({}, {})", chain[length], chain[1], chain[1]));
+    }
+    rng.shuffle(&mut nodes);
+
+    let product: usize = primes[..ghosts].iter().product();
+    Generated {
+        input: format!(
+            "{}\n\n{}\n",
+            instructions.iter().collect::<String>(),
+            nodes.join("\n")
+        ),
+        part1: (primes[0] * rounds).to_string(),
+        part2: (product * rounds).to_string(),
+    }
+}
+
+const EXAMPLE: &str = r#"LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+"#;
+
+const EXAMPLE2: &str = r#"LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+"#;
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: EXAMPLE,
+        part1: Some("6"),
+        part2: None,
+    },
+    Example {
+        input: EXAMPLE2,
+        part1: None,
+        part2: Some("6"),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_example() {
+        // todo
+    }
+
+    #[test]
+    fn reports_unknown_node() {
+        let result = parse_maps("LR\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").err();
+        let expected = PuzzleError::new(
+            8,
+            3,
+            "AAA = (BBB, ZZZ)",
+            "only references to nodes in the network",
+        );
+
+        assert_eq!(result, Some(expected));
+    }
+
+    #[test]
+    fn reports_missing_instructions_and_starts() {
+        let expected = PuzzleError::new(8, 1, "", "at least one instruction");
+        assert_eq!(parse_maps("\n").err(), Some(expected));
+
+        let maps = parse_maps("L\n\nBBB = (BBB, BBB)\n").unwrap();
+        assert_eq!(
+            Day08::solve_part1(&maps),
+            Err(PuzzleError::new(8, 3, "", "a node named `AAA`"))
+        );
+        assert_eq!(
+            Day08::solve_part2(&maps),
+            Err(PuzzleError::new(8, 3, "", "a node ending in `A`"))
+        );
+    }
+
+    #[test]
+    fn example_p1() {
+        let maps = Day08::parse(EXAMPLE).unwrap();
+        let result = Day08::solve_part1(&maps).unwrap();
+        let expected = 6;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn example_p2() {
+        let maps = Day08::parse(EXAMPLE2).unwrap();
+        let result = Day08::solve_part2(&maps).unwrap();
+        let expected = 6;
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,173 @@
+use crate::generator::{Generated, Rng};
+use crate::runner::{Example, ParseSolve, PuzzleError};
+
+const DAY: i32 = 9;
+
+pub struct Day09;
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i64>>, PuzzleError> {
+    input
+        .trim()
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            if line.trim().is_empty() {
+                return Err(PuzzleError::new(DAY, idx + 1, line, "at least one number"));
+            }
+            line.split_ascii_whitespace()
+                .map(|s| {
+                    s.parse()
+                        .map_err(|_| PuzzleError::new(DAY, idx + 1, s, "a number"))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn get_differences(values: &[i64]) -> Vec<i64> {
+    if values.is_empty() {
+        return Vec::with_capacity(0);
+    }
+    let mut result = Vec::with_capacity(values.len() - 1);
+    for idx in 1..values.len() {
+        let diff = values[idx] - values[idx - 1];
+        result.push(diff);
+    }
+
+    result
+}
+
+fn predict(history: &[i64]) -> i64 {
+    let mut lasts = vec![history.last().cloned().unwrap_or(0)];
+
+    let mut current: Vec<i64> = history.to_owned();
+
+    loop {
+        current = get_differences(&current);
+
+        // a short history runs out of differences before they're all zero,
+        // the last row is then taken to repeat
+        let Some(&value) = current.last() else {
+            break;
+        };
+        lasts.push(value);
+
+        if current.iter().all(|v| v == &0) {
+            break;
+        }
+    }
+
+    lasts.into_iter().sum()
+}
+
+fn predict_left(history: &[i64]) -> i64 {
+    let mut firsts = vec![history.first().cloned().unwrap_or(0)];
+    let mut current: Vec<i64> = history.to_owned();
+
+    loop {
+        current = get_differences(&current);
+
+        let Some(&value) = current.first() else {
+            break;
+        };
+        firsts.push(value);
+
+        if current.iter().all(|v| v == &0) {
+            break;
+        }
+    }
+
+    firsts.into_iter().rev().reduce(|acc, v| v - acc).unwrap()
+}
+
+impl ParseSolve<i64, i64> for Day09 {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>, PuzzleError> {
+        parse_input(input)
+    }
+    fn solve_part1(histories: &Vec<Vec<i64>>) -> Result<i64, PuzzleError> {
+        Ok(histories.iter().map(|h| predict(h)).sum())
+    }
+    fn solve_part2(histories: &Vec<Vec<i64>>) -> Result<i64, PuzzleError> {
+        Ok(histories.iter().map(|h| predict_left(h)).sum())
+    }
+}
+
+/// Generates `size` histories, each the values of a polynomial of degree up
+/// to five at `0, 1, ...`, so the predictions are its values one step beyond
+/// either end.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut lines = Vec::new();
+    let (mut part1, mut part2) = (0, 0);
+
+    for _ in 0..size.max(1) {
+        let degree = rng.range(0..=5);
+        let coefficients: Vec<i64> = (0..=degree)
+            .map(|_| rng.range(0..=20) as i64 - 10)
+            .collect();
+        let value = |x: i64| coefficients.iter().rev().fold(0, |sum, &c| sum * x + c);
+
+        let count = rng.range((degree + 2).max(5)..=21) as i64;
+        let values: Vec<_> = (0..count).map(|x| value(x).to_string()).collect();
+        part1 += value(count);
+        part2 += value(-1);
+        lines.push(values.join(" "));
+    }
+
+    Generated {
+        input: lines.join("\n") + "\n",
+        part1: part1.to_string(),
+        part2: part2.to_string(),
+    }
+}
+
+const EXAMPLE: &str = r#"0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+"#;
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        input: EXAMPLE,
+        part1: Some("114"),
+        part2: Some("2"),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_invalid_number() {
+        let result = parse_input("0 3 6\n1 x 6\n");
+        let expected = PuzzleError::new(9, 2, "x", "a number");
+
+        assert_eq!(result, Err(expected));
+    }
+
+    #[test]
+    fn predicts_short_histories() {
+        assert_eq!((predict(&[5]), predict_left(&[5])), (5, 5));
+        assert_eq!((predict(&[1, 2]), predict_left(&[1, 2])), (3, 0));
+    }
+
+    #[test]
+    fn example_p1() {
+        let histories = Day09::parse(EXAMPLE).unwrap();
+        let result = Day09::solve_part1(&histories).unwrap();
+        let expected = 114;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn example_p2() {
+        let histories = Day09::parse(EXAMPLE).unwrap();
+        let result = Day09::solve_part2(&histories).unwrap();
+        let expected = 2;
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,173 @@
+use aoc2023::examples::read_examples;
+use aoc2023::generator::Rng;
+use aoc2023::y2023::day05::{self, Day05};
+use aoc2023::y2023::day06::Day06;
+use aoc2023::{parse_day, solve, solve_year, ParseSolve, SolveError, TrySolve, YEARS};
+
+const DAY06_EXAMPLE: &str = "Time:      7  15   30\nDistance:  9  40  200\n";
+
+fn registered_days() -> impl Iterator<Item = (i32, &'static aoc2023::Day)> {
+    YEARS
+        .iter()
+        .flat_map(|year| year.days.iter().map(move |day| (year.year, day)))
+}
+
+#[test]
+fn solves_registered_examples() {
+    for (year, day) in registered_days() {
+        for example in day.examples {
+            for (part, expected) in [(1, example.part1), (2, example.part2)] {
+                let Some(expected) = expected else {
+                    continue;
+                };
+                let result = solve_year(year, day.day, part, example.input);
+
+                assert_eq!(
+                    result.as_deref(),
+                    Ok(expected),
+                    "{} day {} part {}",
+                    year,
+                    day.day,
+                    part
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn solves_examples_saved_on_windows() {
+    for (year, day) in registered_days() {
+        for example in day.examples {
+            let crlf = example.input.replace('\n', "\r\n");
+            let with_bom = format!("\u{feff}{}", crlf);
+            let trailing_spaces = example.input.replace('\n', "  \n");
+
+            for input in [crlf, with_bom, trailing_spaces] {
+                for (part, expected) in [(1, example.part1), (2, example.part2)] {
+                    let Some(expected) = expected else {
+                        continue;
+                    };
+                    let result = solve_year(year, day.day, part, &input);
+
+                    assert_eq!(
+                        result.as_deref(),
+                        Ok(expected),
+                        "{} day {} part {}",
+                        year,
+                        day.day,
+                        part
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Examples imported from saved puzzle pages are checked too, for the days
+/// that have an examples file.
+#[test]
+fn solves_imported_examples() {
+    for (year, day) in registered_days() {
+        let Ok(examples) = read_examples(year, day.day) else {
+            continue;
+        };
+        for example in examples {
+            for (part, expected) in [(1, example.part1), (2, example.part2)] {
+                let Some(expected) = expected else {
+                    continue;
+                };
+                let result = solve_year(year, day.day, part, &example.input);
+
+                assert_eq!(
+                    result,
+                    Ok(expected),
+                    "{} day {} part {}",
+                    year,
+                    day.day,
+                    part
+                );
+            }
+        }
+    }
+}
+
+/// Generated inputs are checked against the answers they were generated
+/// with, over a few seeds and sizes and once at a size beyond the limits of
+/// some generators.
+#[test]
+fn solves_generated_inputs() {
+    for (year, day) in registered_days() {
+        let Some(generate) = day.generate else {
+            continue;
+        };
+        let runs = (1..=5)
+            .flat_map(|seed| [1, 3, 12].map(|size| (seed, size)))
+            .chain([(1, 5000)]);
+        for (seed, size) in runs {
+            let generated = generate(&mut Rng::new(seed), size);
+            for (part, expected) in [(1, &generated.part1), (2, &generated.part2)] {
+                let result = solve_year(year, day.day, part, &generated.input);
+
+                assert_eq!(
+                    result.as_ref(),
+                    Ok(expected),
+                    "{} day {} part {} seed {} size {}\n{}",
+                    year,
+                    day.day,
+                    part,
+                    seed,
+                    size,
+                    generated.input
+                );
+            }
+        }
+    }
+}
+
+/// The generators that cap their size still produce inputs that solve to
+/// the answers they were generated with when asked for far more.
+#[test]
+fn solves_generated_inputs_of_huge_sizes() {
+    for (year, day) in registered_days().filter(|(_, day)| [3, 5, 7].contains(&day.day)) {
+        let generate = day.generate.expect("days 3, 5 and 7 have generators");
+        let generated = generate(&mut Rng::new(1), usize::MAX);
+        for (part, expected) in [(1, &generated.part1), (2, &generated.part2)] {
+            let result = solve_year(year, day.day, part, &generated.input);
+
+            assert_eq!(
+                result.as_ref(),
+                Ok(expected),
+                "{} day {} part {}",
+                year,
+                day.day,
+                part
+            );
+        }
+    }
+}
+
+#[test]
+fn solves_through_day_types() {
+    let almanac = Day05::parse(day05::EXAMPLES[0].input).unwrap();
+
+    assert_eq!(Day05::solve_part1(&almanac), Ok(35));
+    assert_eq!(Day05::solve_part2(&almanac), Ok(46));
+    assert_eq!(Day06::try_part1(DAY06_EXAMPLE), Ok(288));
+}
+
+#[test]
+fn rejects_unknown_day_and_part() {
+    let day = parse_day("25").unwrap();
+
+    assert_eq!(solve(day, 1, ""), Err(SolveError::NotImplemented(2023, 25)));
+    assert_eq!(
+        solve_year(2015, 1, 1, ""),
+        Err(SolveError::NotImplemented(2015, 1))
+    );
+    assert_eq!(solve(6, 3, DAY06_EXAMPLE), Err(SolveError::NotAPart(3)));
+    assert!(matches!(
+        solve_year(2023, 6, 1, "Time: 7\n"),
+        Err(SolveError::Puzzle(_))
+    ));
+}